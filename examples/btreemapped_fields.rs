@@ -5,10 +5,11 @@ use serde_xml_rs::from_reader;
 
 use std::{collections::BTreeMap, fs::File, io::Read, str::FromStr};
 use utils::{
-    generate_mock_data::generate_mock_data_file, parse_field_bytes, DataTypesCast, RecordDescs,
+    generate_mock_data::generate_mock_data_file, parse_field_bytes, DataTypesCast,
+    DataTypesCastGet, RecordDescs,
 };
 
-use nom::{bytes, IResult};
+use nom::IResult;
 
 use crate::utils::DataTypes; // DataTypesCast is generated by the TypeCast derive macro in the examples/utils/mod.rs file. Rust-Analyzer mistakenly classifies this as an `unresolved import`
 
@@ -21,12 +22,17 @@ impl DataRecord {
     pub fn set_field(&mut self, name: &str, value: DataTypesCast) {
         self.fields.insert(name.to_string(), value);
     }
+
+    // Borrows the value of a field, when the field holds a value of type T
+    pub fn field<T>(&self, name: &str) -> Option<&T>
+    where
+        DataTypesCast: DataTypesCastGet<T>,
+    {
+        self.fields.get(name).and_then(DataTypesCastGet::get)
+    }
 }
 
-fn parse_records<'a>(
-    record_descs: RecordDescs,
-    mut data: &'a [u8],
-) -> IResult<&'a [u8], DataRecord> {
+fn parse_records(record_descs: RecordDescs, mut data: &[u8]) -> IResult<&[u8], DataRecord> {
     let mut data_record = DataRecord::default();
     for record_description in record_descs.record_desc {
         let data_type = record_description.data_type;
@@ -34,47 +40,47 @@ fn parse_records<'a>(
 
         match record_description.number {
             1 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             2 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             3 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             4 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             5 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             6 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             7 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             8 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
             9 => {
-                let (tail, bytes) = data_type.parse(bytes)?;
+                let (_tail, bytes) = data_type.parse(bytes)?;
 
                 data_record.set_field(record_description.name.as_str(), bytes.clone());
             }
@@ -87,7 +93,7 @@ fn parse_records<'a>(
 
         // You can now access the data_record fields here and perform the desired operations
     }
-    return Ok((data, data_record));
+    Ok((data, data_record))
 }
 
 fn main() {
@@ -108,11 +114,11 @@ fn main() {
 
     // Read the binary data file
     let file_contents = std::fs::read(&file_path).expect("Unable to read file");
-    let mut data: &[u8] = &file_contents;
+    let data: &[u8] = &file_contents;
 
     let result = parse_records(record_descs, data);
     match result {
-        Ok((_, data_record)) => {
+        Ok((_, mut data_record)) => {
             println!("\nParsed Values into DataTypesCast Enum:");
             for (name, value) in &data_record.fields {
                 let formatted_name = format!("{:<15}", name);
@@ -149,72 +155,65 @@ fn main() {
                 }
             }
 
-            println!("\nIndividual Value Extraction With TryInto:");
-            let single_val_into: f32 = data_record
+            println!("\nIndividual Value Extraction With TryInto by Reference:");
+            let single_val_into: &f32 = data_record
                 .fields
                 .get("Single")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!("\t{:<15}: {}", "Single", single_val_into);
-            let double_val_into: f64 = data_record
+            let double_val_into: &f64 = data_record
                 .fields
                 .get("Double")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!("\t{:<15}: {}", "Double", double_val_into);
-            let single_arr_val_into: [f32; 2] = data_record
+            let single_arr_val_into: &[f32; 2] = data_record
                 .fields
                 .get("SingleArr")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!(
                 "\t{:<15}: [{}, {}]",
                 "SingleArr", single_arr_val_into[0], single_arr_val_into[1]
             );
-            let double_arr_val_into: [f64; 2] = data_record
+            let double_arr_val_into: &[f64; 2] = data_record
                 .fields
                 .get("DoubleArr")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!(
                 "\t{:<15}: [{}, {}]",
                 "DoubleArr", double_arr_val_into[0], double_arr_val_into[1]
             );
-            let msb_single_arr_val_into: [f32; 2] = data_record
+            let msb_single_arr_val_into: &[f32; 2] = data_record
                 .fields
                 .get("MSBSingleArr")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!(
                 "\t{:<15}: [{:?}, {:?}]",
                 "MSBSingleArr", msb_single_arr_val_into[0], msb_single_arr_val_into[1]
             );
-            let msb_double_arr_val_into: [f64; 2] = data_record
+            let msb_double_arr_val_into: &[f64; 2] = data_record
                 .fields
                 .get("MSBDoubleArr")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!(
                 "\t{:<15}: [{:?}, {:?}]",
                 "MSBDoubleArr", msb_double_arr_val_into[0], msb_double_arr_val_into[1]
             );
-            let lsb_single_arr_3_val_into: [f32; 3] = data_record
+            let lsb_single_arr_3_val_into: &[f32; 3] = data_record
                 .fields
                 .get("LSBSingleArr3")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!(
@@ -224,11 +223,10 @@ fn main() {
                 lsb_single_arr_3_val_into[1],
                 lsb_single_arr_3_val_into[2]
             );
-            let lsb_double_arr_3_val_into: [f64; 3] = data_record
+            let lsb_double_arr_3_val_into: &[f64; 3] = data_record
                 .fields
                 .get("LSBDoubleArr3")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!(
@@ -238,92 +236,116 @@ fn main() {
                 lsb_double_arr_3_val_into[1],
                 lsb_double_arr_3_val_into[2]
             );
-            let ascii_string_val_into: String = data_record
+            let ascii_string_val_into: &String = data_record
                 .fields
                 .get("ASCIIString")
                 .unwrap()
-                .clone()
                 .try_into()
                 .unwrap();
             println!("\t{:<15}: {}", "ASCIIString", ascii_string_val_into);
 
+            println!("\nIndividual Value Extraction With Accessors and DataTypesCastGet:");
+            let single_val = data_record.fields["Single"].as_f32().unwrap();
+            println!("\t{:<15}: {}", "Single", single_val);
+            let double_arr_val = data_record.fields["DoubleArr"].as_f64_array::<2>().unwrap();
+            println!(
+                "\t{:<15}: [{}, {}]",
+                "DoubleArr", double_arr_val[0], double_arr_val[1]
+            );
+            println!(
+                "\t{:<15}: is_ieee754_lsb_single_arr3() = {}",
+                "LSBSingleArr3",
+                data_record.fields["LSBSingleArr3"].is_ieee754_lsb_single_arr3()
+            );
+            let msb_single_arr_val = data_record.field::<[f32; 2]>("MSBSingleArr").unwrap();
+            println!(
+                "\t{:<15}: [{:?}, {:?}]",
+                "MSBSingleArr", msb_single_arr_val[0], msb_single_arr_val[1]
+            );
+            let ascii_string_val = data_record
+                .fields
+                .remove("ASCIIString")
+                .and_then(DataTypesCast::into_string)
+                .unwrap();
+            println!("\t{:<15}: {}", "ASCIIString", ascii_string_val);
+
             println!("\nConvert Str Representations into DataTypes using from_str\n");
 
-            let data_types_IEEE754LSBSingle_from_str = DataTypes::from_str("IEEE754LSBSingle");
+            let data_types_ieee754_lsb_single_from_str = DataTypes::from_str("IEEE754LSBSingle");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754LSBSingle\")",
-                data_types_IEEE754LSBSingle_from_str.unwrap()
+                data_types_ieee754_lsb_single_from_str.unwrap()
             );
 
-            let data_types_IEEE754LSBDouble_from_str = DataTypes::from_str("IEEE754LSBDouble");
+            let data_types_ieee754_lsb_double_from_str = DataTypes::from_str("IEEE754LSBDouble");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754LSBDouble\")",
-                data_types_IEEE754LSBDouble_from_str.unwrap()
+                data_types_ieee754_lsb_double_from_str.unwrap()
             );
 
             println!("\n");
 
-            let data_types_IEEE754LSBSingleArr2_from_str =
+            let data_types_ieee754_lsb_single_arr2_from_str =
                 DataTypes::from_str("IEEE754LSBSingleArr2");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754LSBSingleArr2\")",
-                data_types_IEEE754LSBSingleArr2_from_str.unwrap()
+                data_types_ieee754_lsb_single_arr2_from_str.unwrap()
             );
 
-            let data_types_IEEE754LSBDoubleArr2_from_str =
+            let data_types_ieee754_lsb_double_arr2_from_str =
                 DataTypes::from_str("IEEE754LSBDoubleArr2");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754LSBDoubleArr2\")",
-                data_types_IEEE754LSBDoubleArr2_from_str.unwrap()
+                data_types_ieee754_lsb_double_arr2_from_str.unwrap()
             );
 
             println!("\n");
 
-            let data_types_IEEE754MSBSingleArr2_from_str =
+            let data_types_ieee754_msb_single_arr2_from_str =
                 DataTypes::from_str("IEEE754MSBSingleArr2");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754MSBSingleArr2\")",
-                data_types_IEEE754MSBSingleArr2_from_str.unwrap()
+                data_types_ieee754_msb_single_arr2_from_str.unwrap()
             );
 
-            let data_types_IEEE754MSBDoubleArr2_from_str =
+            let data_types_ieee754_msb_double_arr2_from_str =
                 DataTypes::from_str("IEEE754MSBDoubleArr2");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754MSBDoubleArr2\")",
-                data_types_IEEE754MSBDoubleArr2_from_str.unwrap()
+                data_types_ieee754_msb_double_arr2_from_str.unwrap()
             );
 
             println!("\n");
 
-            let data_types_IEEE754LSBSingleArr3_from_str =
+            let data_types_ieee754_lsb_single_arr3_from_str =
                 DataTypes::from_str("IEEE754LSBSingleArr3");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754LSBSingleArr3\")",
-                data_types_IEEE754LSBSingleArr3_from_str.unwrap()
+                data_types_ieee754_lsb_single_arr3_from_str.unwrap()
             );
 
-            let data_types_IEEE754LSBDoubleArr3_from_str =
+            let data_types_ieee754_lsb_double_arr3_from_str =
                 DataTypes::from_str("IEEE754LSBDoubleArr3");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"IEEE754LSBDoubleArr3\")",
-                data_types_IEEE754LSBDoubleArr3_from_str.unwrap()
+                data_types_ieee754_lsb_double_arr3_from_str.unwrap()
             );
 
             println!("\n");
 
-            let data_types_ASCIIString_from_str = DataTypes::from_str("ASCIIString");
+            let data_types_ascii_string_from_str = DataTypes::from_str("ASCIIString");
             println!(
                 "\t{:<44}:\t{:?}",
                 "DataType::from_str(\"ASCIIString\")",
-                data_types_ASCIIString_from_str.unwrap()
+                data_types_ascii_string_from_str.unwrap()
             );
         }
         Err(e) => {
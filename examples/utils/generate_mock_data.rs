@@ -87,7 +87,7 @@ pub fn generate_mock_data_file(directory: &str, file: &str) {
     let data_file_path = format!("{}/{}", directory, file);
     let file_path = Path::new(&data_file_path);
 
-    if !file_path.exists() || should_overwrite_existing_file(&file_path) {
+    if !file_path.exists() || should_overwrite_existing_file(file_path) {
         let mut file = File::create(&data_file_path).expect("Unable to create the file");
         // Single
         let single: f32 = 123.456;
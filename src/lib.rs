@@ -2,30 +2,45 @@ extern crate proc_macro;
 mod utils;
 
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
 use utils::from_str::generated_from_str_impl;
 
 use std::iter::repeat;
-use syn::{self, Ident};
 
 use crate::utils::{
+    accessors::generate_accessors,
     cast_extraction::get_cast_types,
-    try_into::{build_type_variants_map, generate_try_into_impls},
+    checksum::Checksum,
+    get::generate_get_trait,
+    kind::generate_kind_impl,
+    magic::Magic,
+    numeric::generate_numeric_impl,
+    padding::Padding,
+    try_from::{build_type_variants_map, generate_try_from_impls},
+    type_cast_options::TypeCastOptions,
     CastTypeData,
 };
 
 #[doc = include_str!("doc.md")]
 // Define the `TypeCast` custom derive macro
-#[proc_macro_derive(TypeCast, attributes(cast))]
+#[proc_macro_derive(TypeCast, attributes(cast, type_cast))]
 pub fn derive_macro(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
     let name = &ast.ident;
 
+    // The enum level #[type_cast(...)] attributes configure the generated enum
+    let type_cast_options = match TypeCastOptions::from_attributes(&ast.attrs) {
+        Ok(type_cast_options) => type_cast_options,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let enum_attributes = type_cast_options.enum_attributes();
+    let vis = type_cast_options.visibility();
+
     // Declare mutable variables to store cast types, conversions, and other related information
     let data_type_names = repeat(name.clone());
     let complex_data_type_names = repeat(name.clone());
-    let data_kind_name = format_ident!("{}Cast", name.clone());
+    let data_kind_name = type_cast_options.enum_name(name);
     let data_kind_names = repeat(data_kind_name.clone());
     let complex_data_kind_names = repeat(data_kind_name.clone());
 
@@ -44,12 +59,12 @@ pub fn derive_macro(input: TokenStream) -> TokenStream {
     };
 
     if !errors.is_empty() {
-        return proc_macro2::TokenStream::from_iter(errors.into_iter()).into();
+        return proc_macro2::TokenStream::from_iter(errors).into();
     }
 
-    let type_variants_map = build_type_variants_map(&cast_type_data);
+    let type_variants_map = build_type_variants_map(cast_type_data);
 
-    let generated_try_into_impls = generate_try_into_impls(&type_variants_map, &data_kind_name);
+    let generated_try_from_impls = generate_try_from_impls(&type_variants_map, &data_kind_name);
 
     let CastTypeData {
         cast_types,
@@ -60,49 +75,175 @@ pub fn derive_macro(input: TokenStream) -> TokenStream {
         number_of_array_elements,
         variants,
         complex_variants,
-        string_types,
+        string_types: _,
         string_variants,
-        ..
+        checksums,
+        checksum_variants,
+        paddings,
+        padding_variants,
+        magics,
+        magic_variants,
+        ignored_variants,
     } = &cast_type_data;
-    let mut parser_types: Vec<Ident> = Vec::new();
-    for (cast_type, conversion) in cast_types.iter().zip(conversion) {
-        let cast_type = cast_type.to_string();
-        let conversion = conversion.to_string();
-        parser_types.push(Ident::new(
-            &format!("{conversion}{cast_type}"),
-            Span::call_site(),
-        ))
-    }
-    let mut complex_parser_types: Vec<Ident> = Vec::new();
-    for (cast_type, conversion) in complex_cast_types.iter().zip(complex_conversion) {
-        let cast_type = cast_type.to_string();
-        let conversion = conversion.to_string();
-        complex_parser_types.push(Ident::new(
-            &format!("{conversion}{cast_type}"),
-            Span::call_site(),
-        ))
-    }
+    let parser_types: Vec<TokenStream2> = cast_types
+        .iter()
+        .zip(conversion)
+        .map(|(cast_type, conversion)| cast_type.parser(conversion))
+        .collect();
+    // Values that must match, either a magic signature or a scalar with an `expect` option, are reported with a type generated next to the enum
+    let unexpected_value_name = format_ident!("{}UnexpectedValue", data_kind_name);
+    let expect_checks: Vec<TokenStream2> = cast_types
+        .iter()
+        .zip(variants)
+        .map(|(cast_type, variant)| {
+            cast_type
+                .options
+                .expect_check(&cast_type.element, variant, &unexpected_value_name)
+        })
+        .collect();
+    let ignored_data_type_names = repeat(name.clone());
+    let magic_parsers: Vec<TokenStream2> = magics.iter().map(Magic::parser).collect();
+    let magic_signatures = magics.iter().map(|magic| &magic.signature);
+    let magic_data_type_names = repeat(name.clone());
+    let magic_data_kind_names = repeat(data_kind_name.clone());
+    let unexpected_value_names = repeat(unexpected_value_name.clone());
+    let expects_values = !magics.is_empty()
+        || cast_types
+            .iter()
+            .any(|cast_type| cast_type.options.expect.is_some());
+    let (unexpected_value_bounds, unexpected_value) = if expects_values {
+        (
+            quote! { + ::nom::error::FromExternalError<&'a [u8], #unexpected_value_name> },
+            quote! {
+                // The value a variant requires and the value found in the input, when they differ
+                #[derive(Clone, Debug, PartialEq, Eq)]
+                #vis struct #unexpected_value_name {
+                    pub variant: &'static str,
+                    pub expected: ::std::string::String,
+                    pub found: ::std::string::String,
+                }
+
+                impl ::std::fmt::Display for #unexpected_value_name {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        ::std::write!(f, "{}: expected {}, found {}", self.variant, self.expected, self.found)
+                    }
+                }
+
+                impl ::std::error::Error for #unexpected_value_name {}
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let complex_parser_types: Vec<TokenStream2> = complex_cast_types
+        .iter()
+        .zip(complex_conversion)
+        .zip(number_of_array_elements)
+        .map(|((cast_type, conversion), length)| cast_type.array_parser(conversion, length))
+        .collect();
+
+    let checksum_types: Vec<Ident> = checksums.iter().map(Checksum::value_type).collect();
+    let checksum_stored_parsers: Vec<TokenStream2> =
+        checksums.iter().map(Checksum::stored_parser).collect();
+    let checksum_computations: Vec<TokenStream2> =
+        checksums.iter().map(Checksum::compute).collect();
+    let checksum_starts = checksums.iter().map(|checksum| &checksum.start);
+    let checksum_ends = checksums.iter().map(|checksum| &checksum.end);
+    let checksum_data_type_names = repeat(name.clone());
+    let checksum_data_kind_names = repeat(data_kind_name.clone());
+
+    let padding_parsers: Vec<TokenStream2> = paddings.iter().map(Padding::parser).collect();
+    let has_align = paddings.iter().any(Padding::is_align);
+    let padding_data_type_names = repeat(name.clone());
+    let padding_data_kind_names = repeat(data_kind_name.clone());
+
+    // Failed checksums are reported with a type generated next to the enum, only when it has checksum variants
+    let checksum_mismatch_name = format_ident!("{}ChecksumMismatch", data_kind_name);
+    let checksum_mismatch_names = repeat(checksum_mismatch_name.clone());
+    let (external_error_bounds, checksum_mismatch) = if checksums.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        (
+            quote! { + ::nom::error::FromExternalError<&'a [u8], #checksum_mismatch_name> },
+            quote! {
+                // The checksum stored in the input and the checksum computed over the bytes it covers, when they differ
+                #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+                #vis struct #checksum_mismatch_name {
+                    pub variant: &'static str,
+                    pub expected: u32,
+                    pub actual: u32,
+                }
+
+                impl ::std::fmt::Display for #checksum_mismatch_name {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        ::std::write!(f, "{} checksum mismatch: expected {:#x}, computed {:#x}", self.variant, self.expected, self.actual)
+                    }
+                }
+
+                impl ::std::error::Error for #checksum_mismatch_name {}
+            },
+        )
+    };
+
+    // `align` padding needs the offset of the input within the record, so an enum with `align` variants is
+    // only parsed through `parse_at`, rather than silently measuring from an offset of 0 in `parse`
+    let (offset, offset_free_parsers) = if has_align {
+        (format_ident!("offset"), quote! {})
+    } else {
+        (
+            format_ident!("_offset"),
+            quote! {
+                #vis fn parse(self, input: &[u8]) -> ::nom::IResult<&[u8], #data_kind_name> {
+                    self.parse_with(input)
+                }
+
+                // Parse with a nom error type chosen by the caller, which receives failed checksums through `FromExternalError`
+                #vis fn parse_with<'a, E>(self, input: &'a [u8]) -> ::nom::IResult<&'a [u8], #data_kind_name, E>
+                where
+                    E: ::nom::error::ParseError<&'a [u8]> #external_error_bounds #unexpected_value_bounds,
+                {
+                    self.parse_at_with(input, 0)
+                }
+            },
+        )
+    };
 
     let gen = quote! {
 
-        // Derive common traits and define the enum with primitive, complex, and string type variants. The enum's name is generated by appending "Cast" to the name of the enum decorated with #[derive(TypeCast)]
-        #[derive(Clone, Debug, Serialize, Deserialize)]
-        pub enum #data_kind_name {
+        // Derive the configured traits, common ones by default, and define the enum with primitive, complex, and string type variants. The enum's name is generated by appending "Cast" to the name of the enum decorated with #[derive(TypeCast)]
+        #enum_attributes
+        #vis enum #data_kind_name {
             #(#variants(#cast_types),)*
             #(#complex_variants(#complex_cast_types_group),)*
-            #(#string_variants(#string_types),)*
+            #(#string_variants(::std::string::String),)*
+            #(#checksum_variants(#checksum_types),)*
+            #(#padding_variants,)*
+            #(#magic_variants,)*
         }
 
         // Implement the parse method for the enum decorated with #[derive(TypeCast)]
         impl #name  {
-            pub fn parse(self, input: &[u8]) -> IResult<&[u8], #data_kind_name> {
+            #offset_free_parsers
+
+            // Parse input found at `offset` bytes into the record, which `align` padding is measured from
+            #vis fn parse_at(self, input: &[u8], offset: usize) -> ::nom::IResult<&[u8], #data_kind_name> {
+                self.parse_at_with(input, offset)
+            }
+
+            // Parse input found at `offset` bytes into the record with a nom error type chosen by the caller
+            #vis fn parse_at_with<'a, E>(self, input: &'a [u8], #offset: usize) -> ::nom::IResult<&'a [u8], #data_kind_name, E>
+            where
+                E: ::nom::error::ParseError<&'a [u8]> #external_error_bounds #unexpected_value_bounds,
+            {
                 // Match the current variant of the enum decorated with #[derive(TypeCast)] and convert the input bytes accordingly
                 match self {
                     #(
                         #data_type_names::#variants => {
-                            let (tail, bytes) = nom::number::complete::#parser_types(input)?;
+                            let (tail, bytes) = #parser_types(input)?;
+                            #expect_checks
 
-                        Ok((tail, #data_kind_names::#variants (
+                        ::std::result::Result::Ok((tail, #data_kind_names::#variants (
 
                             bytes
                         )))
@@ -111,11 +252,11 @@ pub fn derive_macro(input: TokenStream) -> TokenStream {
                     )*
                     #(
                         #complex_data_type_names::#complex_variants => {
-                            let (tail, elements_vec) = nom::multi::count(nom::number::complete::#complex_parser_types, #number_of_array_elements)(input)?;
+                            let (tail, elements_vec) = #complex_parser_types(input)?;
 
-                            let out: [#complex_cast_types; #number_of_array_elements] = elements_vec.try_into().map_err(|_| nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Fail)))?;
+                            let out: [#complex_cast_types; #number_of_array_elements] = ::std::convert::TryInto::try_into(elements_vec).map_err(|_| ::nom::Err::Failure(E::from_error_kind(input, ::nom::error::ErrorKind::Fail)))?;
 
-                            Ok((tail, #complex_data_kind_names::#complex_variants(out)))
+                            ::std::result::Result::Ok((tail, #complex_data_kind_names::#complex_variants(out)))
                         },
                     )*
                     // Handle string types
@@ -123,22 +264,85 @@ pub fn derive_macro(input: TokenStream) -> TokenStream {
                         #string_data_kind_names::#string_variants => {
 
 
-                        let (tail,bytes) = nom::bytes::complete::take_while1(|c:u8| c.is_ascii())(input)?;
-                        let string_result = String::from_utf8(bytes.to_vec()).map_err(|_| nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Fail)))?;
+                        let (tail,bytes) = ::nom::bytes::complete::take_while1(|c:u8| c.is_ascii())(input)?;
+                        let string_result = ::std::string::String::from_utf8(bytes.to_vec()).map_err(|_| ::nom::Err::Failure(E::from_error_kind(input, ::nom::error::ErrorKind::Fail)))?;
 
-                        Ok((tail,#data_kind_name::#string_variants(string_result)))
+                        ::std::result::Result::Ok((tail,#data_kind_name::#string_variants(string_result)))
                     }
                     )*
+                    // Handle checksums, which are stored immediately after the bytes they cover
+                    #(
+                        #checksum_data_type_names::#checksum_variants => {
+                            let covered = input.get(#checksum_starts..#checksum_ends).ok_or_else(|| ::nom::Err::Error(E::from_error_kind(input, ::nom::error::ErrorKind::Eof)))?;
+                            let (tail, expected) = #checksum_stored_parsers(&input[#checksum_ends..])?;
+                            let actual: #checksum_types = { #checksum_computations };
+                            if actual != expected {
+                                return ::std::result::Result::Err(::nom::Err::Error(E::from_external_error(
+                                    input,
+                                    ::nom::error::ErrorKind::Verify,
+                                    #checksum_mismatch_names { variant: ::std::stringify!(#checksum_variants), expected: expected.into(), actual: actual.into() },
+                                )));
+                            }
+
+                            ::std::result::Result::Ok((tail, #checksum_data_kind_names::#checksum_variants(expected)))
+                        }
+                    )*
+                    // Handle reserved bytes, which are consumed and leave a unit variant
+                    #(
+                        #padding_data_type_names::#padding_variants => {
+                            let (tail, _) = (#padding_parsers)(input)?;
+
+                            ::std::result::Result::Ok((tail, #padding_data_kind_names::#padding_variants))
+                        }
+                    )*
+                    // Handle signatures, which must match the input byte for byte
+                    #(
+                        #magic_data_type_names::#magic_variants => {
+                            let (tail, found): (&[u8], &[u8]) = #magic_parsers(input)?;
+                            if found != #magic_signatures {
+                                return ::std::result::Result::Err(::nom::Err::Error(E::from_external_error(
+                                    input,
+                                    ::nom::error::ErrorKind::Verify,
+                                    #unexpected_value_names {
+                                        variant: ::std::stringify!(#magic_variants),
+                                        expected: ::std::format!("b\"{}\"", #magic_signatures.escape_ascii()),
+                                        found: ::std::format!("b\"{}\"", found.escape_ascii()),
+                                    },
+                                )));
+                            }
+
+                            ::std::result::Result::Ok((tail, #magic_data_kind_names::#magic_variants))
+                        }
+                    )*
+                    // Variants marked #[cast(ignore)] are excluded from parsing and always fail
+                    #(
+                        #ignored_data_type_names::#ignored_variants => {
+                            ::std::result::Result::Err(::nom::Err::Error(E::from_error_kind(input, ::nom::error::ErrorKind::Fail)))
+                        }
+                    )*
                  }
             }
         }
+
+        #checksum_mismatch
+        #unexpected_value
     };
 
     let generated_from_str_impl = generated_from_str_impl(name, cast_type_data);
 
+    let generated_kind_impl = generate_kind_impl(name, &data_kind_name, &vis, cast_type_data);
+    let generated_accessors =
+        generate_accessors(&type_variants_map, &data_kind_name, &vis, cast_type_data);
+    let generated_get_trait = generate_get_trait(&type_variants_map, &data_kind_name, &vis);
+    let generated_numeric_impl = generate_numeric_impl(&data_kind_name, &vis, cast_type_data);
+
     let combined_gen = quote! {
         #gen
-        #generated_try_into_impls
+        #generated_kind_impl
+        #generated_accessors
+        #generated_get_trait
+        #generated_numeric_impl
+        #generated_try_from_impls
         #generated_from_str_impl
     };
     combined_gen.into()
@@ -0,0 +1,173 @@
+// accessors.rs
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashMap};
+
+use super::CastTypeData;
+
+/// Generates typed accessors on the generated enum from the map of cast types to the variants
+/// holding them:
+///
+/// * `as_<type>(&self) -> Option<&T>` and `into_<type>(self) -> Option<T>` for every scalar type,
+///   e.g. `as_f32` or `into_string`
+/// * `as_<element>_array::<N>(&self) -> Option<&[T; N]>` and `into_<element>_array::<N>(self)`
+///   for every array element type, matching the arrays of length `N`
+/// * `is_<variant>(&self) -> bool` for every variant, e.g. `is_ieee754_lsb_single`
+///
+/// Types whose accessor names would collide, such as `a::Mode` and `b::Mode`, get no accessors.
+pub fn generate_accessors(
+    type_variants_map: &HashMap<String, Vec<Ident>>,
+    data_kind_name: &Ident,
+    vis: &syn::Visibility,
+    cast_type_data: &CastTypeData,
+) -> TokenStream2 {
+    // Group the cast types by the name of their accessors, sorted so the output is stable
+    let mut scalars: BTreeMap<String, Vec<(syn::Type, &Vec<Ident>)>> = BTreeMap::new();
+    let mut arrays: BTreeMap<String, Vec<(syn::Type, &Vec<Ident>)>> = BTreeMap::new();
+    for (cast_type_str, variants) in type_variants_map {
+        let Ok(cast_type) = syn::parse_str::<syn::Type>(cast_type_str) else {
+            continue;
+        };
+        match &cast_type {
+            syn::Type::Array(array) => {
+                if let Some(slug) = type_slug(&array.elem) {
+                    let element = (*array.elem).clone();
+                    arrays.entry(slug).or_default().push((element, variants));
+                }
+            }
+            cast_type => {
+                if let Some(slug) = type_slug(cast_type) {
+                    scalars
+                        .entry(slug)
+                        .or_default()
+                        .push((cast_type.clone(), variants));
+                }
+            }
+        }
+    }
+
+    let mut accessors = Vec::new();
+    for (slug, types) in &scalars {
+        let [(cast_type, variants)] = types.as_slice() else {
+            continue;
+        };
+        let as_name = format_ident!("as_{}", slug);
+        let into_name = format_ident!("into_{}", slug);
+        accessors.push(quote! {
+            #vis fn #as_name(&self) -> ::std::option::Option<&#cast_type> {
+                match self {
+                    #(#data_kind_name::#variants(val) => ::std::option::Option::Some(val),)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            #vis fn #into_name(self) -> ::std::option::Option<#cast_type> {
+                match self {
+                    #(#data_kind_name::#variants(val) => ::std::option::Option::Some(val),)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+        });
+    }
+    for (slug, types) in &arrays {
+        // Arrays of the same element with different lengths share the accessors
+        let element = &types[0].0;
+        if types.iter().any(|(other, _)| other != element) {
+            continue;
+        }
+        let variants: Vec<&Ident> = types.iter().flat_map(|(_, variants)| *variants).collect();
+        let as_name = format_ident!("as_{}_array", slug);
+        let into_name = format_ident!("into_{}_array", slug);
+        accessors.push(quote! {
+            #vis fn #as_name<const N: usize>(&self) -> ::std::option::Option<&[#element; N]> {
+                match self {
+                    #(#data_kind_name::#variants(val) => <&[#element; N] as ::std::convert::TryFrom<&[#element]>>::try_from(&val[..]).ok(),)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            #vis fn #into_name<const N: usize>(self) -> ::std::option::Option<[#element; N]> {
+                match self {
+                    #(#data_kind_name::#variants(val) => <[#element; N] as ::std::convert::TryFrom<::std::vec::Vec<#element>>>::try_from(::std::vec::Vec::from(val)).ok(),)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+        });
+    }
+
+    // Every variant of the generated enum gets a predicate, whether or not it holds a value
+    let valued = cast_type_data
+        .variants
+        .iter()
+        .chain(&cast_type_data.complex_variants)
+        .chain(&cast_type_data.string_variants)
+        .chain(&cast_type_data.checksum_variants)
+        .map(|variant| (variant, quote! { (..) }));
+    let unit = cast_type_data
+        .padding_variants
+        .iter()
+        .chain(&cast_type_data.magic_variants)
+        .map(|variant| (variant, quote! {}));
+    for (variant, fields) in valued.chain(unit) {
+        let is_name = Ident::new(
+            &format!("is_{}", snake_case(&variant.to_string())),
+            Span::call_site(),
+        );
+        accessors.push(quote! {
+            #vis fn #is_name(&self) -> bool {
+                ::std::matches!(self, #data_kind_name::#variant #fields)
+            }
+        });
+    }
+
+    quote! {
+        impl #data_kind_name {
+            #(#accessors)*
+        }
+    }
+}
+
+// Names the accessors of a type after its last path segment and generic arguments, e.g.
+// `Option<f32>` becomes `option_f32`. Types that cannot be named this way have no accessors
+fn type_slug(cast_type: &syn::Type) -> Option<String> {
+    let syn::Type::Path(path) = cast_type else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let mut slug = snake_case(&segment.ident.to_string());
+    if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+        for argument in &arguments.args {
+            let syn::GenericArgument::Type(argument) = argument else {
+                return None;
+            };
+            slug.push('_');
+            slug.push_str(&type_slug(argument)?);
+        }
+    }
+    Some(slug)
+}
+
+// Converts a CamelCase name to snake_case, keeping runs of capitals together, e.g.
+// `IEEE754LSBSingle` becomes `ieee754_lsb_single`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
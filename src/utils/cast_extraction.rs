@@ -1,20 +1,240 @@
-use super::CastTypeData;
-use proc_macro2::{Span, TokenTree};
-use syn::Ident;
+use super::{
+    checksum::CHECKSUM_ALGORITHMS,
+    checksum::{is_checksum, Checksum},
+    element::{enabled_kinds, CastType, ElementType, KINDS, PRIMITIVE_TYPES},
+    legacy_float::{is_legacy_float, LEGACY_FLOAT_CONVERSIONS},
+    magic::Magic,
+    options::CastOptions,
+    padding::{is_padding, Padding, PADDING_KINDS},
+    suggest::did_you_mean,
+    CastTypeData,
+};
+use proc_macro2::{Delimiter, Group, Span};
+use quote::quote;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    Ident, Token,
+};
 
-/// Represents the processing states for cast type attributes.
-enum ProcessingState {
-    None,
-    AwaitingType,
-    AwaitingConversion,
+/// The conversions written before `=>`, paired with the byte order prefix of the nom parsers they
+/// select. The legacy floating point formats are conversions too, named after the format itself.
+const CONVERSIONS: [(&str, &str); 3] = [
+    ("from_le_bytes", "le_"),
+    ("from_be_bytes", "be_"),
+    ("from_me_bytes", "me_"),
+];
+
+/// The keywords of the variant kinds that are not followed by a cast type
+const STANDALONE_KINDS: [&str; 3] = ["magic", "String", "ignore"];
+
+/// Represents the contents of a `cast` attribute. Every kind of variant is recognised by its
+/// leading keyword in `CastAttribute::parse`, which is the one place new kinds are added.
+enum CastAttribute {
+    /// A conversion followed by a scalar cast type, e.g. `from_le_bytes => f32, into = Mode`
+    Scalar {
+        conversion: Ident,
+        cast_type: CastType,
+    },
+    /// A conversion followed by an array cast type, e.g. `from_be_bytes => [u16; 4]`
+    Array {
+        conversion: Ident,
+        cast_type: CastType,
+        length: Box<syn::Expr>,
+    },
+    /// A kind read without a conversion, e.g. `ipv4`
+    Kind(Ident, ElementType),
+    /// An ASCII string
+    String(Ident),
+    Checksum(Checksum),
+    Padding(Padding),
+    Magic(Magic),
+    /// A variant excluded from parsing
+    Ignore,
+}
+
+impl Parse for CastAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = input.fork().parse().map_err(|error| {
+            syn::Error::new(
+                error.span(),
+                "Expected a conversion such as from_le_bytes or a variant kind such as String",
+            )
+        })?;
+        let name = keyword.to_string();
+        let conversion = CONVERSIONS
+            .iter()
+            .find(|(method, _)| *method == name)
+            .map(|(_, prefix)| prefix.to_string())
+            .or_else(|| is_legacy_float(&name).then(|| name.clone()));
+        if let Some(conversion) = conversion {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=>]>()?;
+            return parse_cast_type(Ident::new(&conversion, Span::call_site()), input);
+        }
+        if is_checksum(&name) {
+            return Ok(CastAttribute::Checksum(input.parse()?));
+        }
+        if is_padding(&name) {
+            return Ok(CastAttribute::Padding(input.parse()?));
+        }
+        if name == "magic" {
+            return Ok(CastAttribute::Magic(input.parse()?));
+        }
+        input.parse::<Ident>()?;
+        if KINDS.contains(&name.as_str()) {
+            let element = ElementType::from_kind(&keyword)?;
+            return Ok(CastAttribute::Kind(keyword, element));
+        }
+        if name == "String" {
+            return Ok(CastAttribute::String(keyword));
+        }
+        if name == "ignore" {
+            return Ok(CastAttribute::Ignore);
+        }
+        if PRIMITIVE_TYPES.contains(&name.as_str()) || name == "Complex" {
+            return Err(syn::Error::new(
+                keyword.span(),
+                format!(
+                    "Expected a conversion before the cast type {}\nnote: cast types follow a conversion, e.g. #[cast(from_le_bytes => {})]",
+                    name, name
+                ),
+            ));
+        }
+        let conversions = conversion_names();
+        let mut kinds: Vec<&str> = STANDALONE_KINDS.to_vec();
+        kinds.extend(enabled_kinds());
+        kinds.extend(CHECKSUM_ALGORITHMS);
+        kinds.extend(PADDING_KINDS);
+        let keywords: Vec<&str> = conversions.iter().chain(&kinds).copied().collect();
+        Err(syn::Error::new(
+            keyword.span(),
+            format!(
+                "Expected valid conversion or valid cast type, found {}{}\nnote: the accepted conversions are {}\nnote: the accepted variant kinds are {}",
+                name,
+                did_you_mean(&name, &keywords),
+                conversions.join(", "),
+                kinds.join(", ")
+            ),
+        ))
+    }
+}
+
+// The conversions written before `=>`, the byte orders followed by the legacy floating point formats
+fn conversion_names() -> Vec<&'static str> {
+    let mut names: Vec<&str> = CONVERSIONS.iter().map(|(method, _)| *method).collect();
+    for (legacy, _) in LEGACY_FLOAT_CONVERSIONS {
+        if !names.contains(&legacy) {
+            names.push(legacy);
+        }
+    }
+    names
+}
+
+/// Parses the cast type following `=>` and its options. Arrays are written as `[element; length]`
+/// with any const expression as the length, anything else is a scalar element.
+fn parse_cast_type(conversion: Ident, input: ParseStream) -> syn::Result<CastAttribute> {
+    if input.peek(syn::token::Bracket) {
+        let content;
+        let bracket = bracketed!(content in input);
+        let element: ElementType = content.parse()?;
+        content.parse::<Token![;]>()?;
+        let length: syn::Expr = content.parse()?;
+        if !content.is_empty() {
+            return Err(content.error("Expected the array to end after its length"));
+        }
+        let options = CastOptions::parse_trailing(input)?;
+        element.check_conversion(&conversion)?;
+        options.check_element(&element)?;
+        if let Some(expect) = &options.expect {
+            return Err(syn::Error::new_spanned(
+                expect,
+                "`expect` can only be applied to scalar cast types",
+            ));
+        }
+        if matches!(element, ElementType::Bool(_)) != options.bitmask {
+            return Err(bitmask_error(bracket.span));
+        }
+        return Ok(CastAttribute::Array {
+            conversion,
+            cast_type: CastType { element, options },
+            length: Box::new(length),
+        });
+    }
+    let span = input.span();
+    let element: ElementType = input.parse()?;
+    let options = CastOptions::parse_trailing(input)?;
+    element.check_conversion(&conversion)?;
+    options.check_element(&element)?;
+    if matches!(element, ElementType::Bool(_)) || options.bitmask {
+        return Err(bitmask_error(span));
+    }
+    Ok(CastAttribute::Scalar {
+        conversion,
+        cast_type: CastType { element, options },
+    })
+}
+
+impl CastAttribute {
+    /// Records the parsed attribute of the variant in the vectors of `cast_type_data`
+    fn push(self, variant: &Ident, cast_type_data: &mut CastTypeData) {
+        match self {
+            CastAttribute::Scalar {
+                conversion,
+                cast_type,
+            } => {
+                cast_type_data.cast_types.push(cast_type);
+                cast_type_data.variants.push(variant.clone());
+                cast_type_data.conversion.push(conversion);
+            }
+            CastAttribute::Array {
+                conversion,
+                cast_type,
+                length,
+            } => {
+                cast_type_data
+                    .complex_cast_types_group
+                    .push(Group::new(Delimiter::Bracket, quote!(#cast_type; #length)));
+                cast_type_data.complex_cast_types.push(cast_type);
+                cast_type_data.number_of_array_elements.push(*length);
+                cast_type_data.complex_variants.push(variant.clone());
+                cast_type_data.complex_conversion.push(conversion);
+            }
+            CastAttribute::Kind(kind, element) => {
+                cast_type_data.cast_types.push(CastType {
+                    element,
+                    options: CastOptions::default(),
+                });
+                cast_type_data.variants.push(variant.clone());
+                cast_type_data.conversion.push(kind);
+            }
+            CastAttribute::String(string_type) => {
+                cast_type_data.string_types.push(string_type);
+                cast_type_data.string_variants.push(variant.clone());
+            }
+            CastAttribute::Checksum(checksum) => {
+                cast_type_data.checksums.push(checksum);
+                cast_type_data.checksum_variants.push(variant.clone());
+            }
+            CastAttribute::Padding(padding) => {
+                cast_type_data.paddings.push(padding);
+                cast_type_data.padding_variants.push(variant.clone());
+            }
+            CastAttribute::Magic(magic) => {
+                cast_type_data.magics.push(magic);
+                cast_type_data.magic_variants.push(variant.clone());
+            }
+            CastAttribute::Ignore => cast_type_data.ignored_variants.push(variant.clone()),
+        }
+    }
 }
 
 /// Parses the custom `cast` attribute for each variant of the given data enum
 /// and updates the provided `cast_type_data` with the parsed information.
 ///
 /// This function iterates through the enum variants, filtering for the "cast"
-/// attribute. For each "cast" attribute found, it processes its tokens and
-/// updates the `cast_type_data` accordingly.
+/// attribute. For each "cast" attribute found, it parses its arguments as a
+/// `CastAttribute` and updates the `cast_type_data` accordingly.
 ///
 /// # Arguments
 ///
@@ -31,115 +251,52 @@ pub fn get_cast_types(
 ) -> Vec<proc_macro2::TokenTree> {
     let mut errors = Vec::new();
 
-    // Iterate over each variant in the enum
-    data_enum.variants.into_iter().for_each(|variant| {
-        // Iterate over the attributes of the variant, filtering only the ones with the "cast" identifier
-        
-        let mut current_conversion = String::new();
-        variant.attrs.into_iter().filter(|attr| attr.path.is_ident("cast")).for_each(|attr| {
-            let mut processing_state = ProcessingState::None;
-        
-            if let TokenTree::Group(group) = attr.tokens.into_iter().next().unwrap() {
-                
-                let mut punctuations = Vec::new();
-                // Iterate through the tokens in the attribute group
-                group.stream().into_iter().for_each(|stream| {
-                    match (stream.clone(),&processing_state) {
-                        (TokenTree::Ident(ref ident),ProcessingState::None) => {
-                            let ident_str = ident.to_string();
-                            match ident_str.as_str() {
-                                // Match the conversion methods
-                                
-                                "from_le_bytes" => {
-                                    current_conversion = "le_".to_string();
-                                    processing_state = ProcessingState::AwaitingType;
-
-                                },
-                                "from_be_bytes" => {
-                                    current_conversion = "be_".to_string();
-                                    processing_state = ProcessingState::AwaitingType;
-                                },
-                                // Match the String data type
-                                "String" => {
-                                    cast_type_data.string_types.push(ident.clone());
-                                    cast_type_data.string_variants.push(variant.ident.clone());
-                                    
-                                    processing_state = ProcessingState::None;
-                                },
-                               
-                                // Handle invalid conversion or cast types
-                                i => errors.extend(syn::Error::new(ident.span(), format!("Expected valid conversion or valid cast type, found {}", i)).to_compile_error()),
-                            }
-                        },
-                        
-                        // Ensure that the only punctuation between the conversion method and the data type are consistent with `=>`
-                        (TokenTree::Punct(ref punct),ProcessingState::AwaitingType) => {
-                            if punct.as_char() == '=' || punct.as_char() == '>' {
-                                punctuations.push(punct.as_char());
-                                
-                            } else {
-                                errors.extend(syn::Error::new(punct.span(), format!("Expected '=' or '>', found '{}'", punct.as_char())).to_compile_error());
-                            }
-                            if punctuations == ['=','>'] {
-                                processing_state = ProcessingState::AwaitingConversion;
-                            }
-                        },
-                        (TokenTree::Ident(ref ident),ProcessingState::AwaitingConversion) => {
-                            let ident_str = ident.to_string();
-                            match ident_str.as_str() {
-                               
-                                // Match the basic data types
-                                "f32" | "f64" | "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" => {
-                                    cast_type_data.cast_types.push(ident.clone());
-                                    cast_type_data.variants.push(variant.ident.clone());
-                                    cast_type_data.conversion.push(Ident::new(&current_conversion.clone(),Span::call_site()));
-                                    current_conversion.clear();
-                                    processing_state = ProcessingState::None;
-
-                                },
-                                
-                                // Handle invalid conversion or cast types
-                                i => errors.extend(syn::Error::new(ident.span(), format!("Expected valid conversion or valid cast type, found {}", i)).to_compile_error()),
-                            }
-                        },
-                        // Handle complex cast types (arrays)
-                        (TokenTree::Group(array_group),ProcessingState::AwaitingConversion) => {
-                            cast_type_data.complex_cast_types_group.push(array_group.clone());
-                            
-
-                            // Iterate through the tokens in the array group
-                            array_group.stream().into_iter().for_each(|array_stream| {
-                                match array_stream.clone() {
-                                    TokenTree::Ident(ref ident) => {
-                                        cast_type_data.complex_cast_types.push(ident.clone());
-                                        cast_type_data.complex_variants.push(variant.ident.clone());
-                                    },
-                                    TokenTree::Punct(_) => (),
-                                    TokenTree::Literal(ref literal) => {
-                                        cast_type_data.number_of_array_elements.push(literal.clone());
-                                    },
-                                    // Handle unexpected tokens in the array group
-                                    tt => errors.extend(syn::Error::new(array_stream.span(), format!("Expected '' found {}", tt)).to_compile_error()),
-                                }
-                            });
-                            cast_type_data.complex_conversion.push(Ident::new(&current_conversion.clone(),Span::call_site()));
-                                    current_conversion.clear();
-                            processing_state = ProcessingState::None;
-                        },
-                        // Handle unexpected tokens in the attribute group
-                        (tt,_) => errors.extend(syn::Error::new(stream.span(), tt.to_string()).to_compile_error()),
-                    }
-                });
-                let valid_arrows = punctuations.windows(2).all(|window| window[0] == '=' && window[1] == '>');
-                if !valid_arrows {
-                    errors.extend(syn::Error::new(group.span(), "Expected '=>', found different order or extra characters").to_compile_error());
-                } else {
-                    punctuations.clear();
-                }
-            }
-        });
-    });
+    for variant in data_enum.variants {
+        // Every variant needs exactly one attribute with the "cast" identifier, so the vectors of `cast_type_data` stay aligned
+        let mut cast_attrs = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cast"));
+        let Some(attr) = cast_attrs.next() else {
+            errors.extend(
+                syn::Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "Variant `{}` requires a #[cast(...)] attribute, or #[cast(ignore)] to exclude it from parsing",
+                        variant.ident
+                    ),
+                )
+                .to_compile_error(),
+            );
+            continue;
+        };
+        if let Some(extra) = cast_attrs.next() {
+            errors.extend(
+                syn::Error::new_spanned(
+                    extra,
+                    format!(
+                        "Variant `{}` has more than one #[cast(...)] attribute",
+                        variant.ident
+                    ),
+                )
+                .to_compile_error(),
+            );
+            continue;
+        }
+        match attr.parse_args::<CastAttribute>() {
+            Ok(cast_attribute) => cast_attribute.push(&variant.ident, cast_type_data),
+            Err(error) => errors.extend(error.to_compile_error()),
+        }
+    }
 
     // Return the errors encountered during processing
     errors
 }
+
+// Bitmasks and bool elements are only valid together, as an array expanded from bits
+fn bitmask_error(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "bool arrays are expanded from bitmasks and require both the `bitmask` option and a type such as [bool; 16]",
+    )
+}
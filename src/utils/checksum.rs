@@ -0,0 +1,312 @@
+// checksum.rs
+use proc_macro2::{Ident, Literal, Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::{options::one_of, suggest::did_you_mean};
+
+/// The checksum algorithms accepted as a variant kind, e.g. `#[cast(crc32 = "ieee", over = 0..28)]`
+pub const CHECKSUM_ALGORITHMS: [&str; 4] = ["crc16", "crc32", "fletcher16", "fletcher32"];
+
+/// The options accepted after the checksum algorithm
+const CHECKSUM_OPTIONS: [&str; 2] = ["over", "byte_order"];
+
+/// The byte orders the stored checksum can be read in
+const BYTE_ORDERS: [&str; 2] = ["be", "le"];
+
+/// The parameters of a CRC, following the conventions of the CRC catalogue
+struct CrcParameters {
+    algorithm: &'static str,
+    name: &'static str,
+    poly: u32,
+    init: u32,
+    /// Whether the input and output are bit reflected
+    reflected: bool,
+    xor_out: u32,
+}
+
+/// The supported CRCs. The first listed for each algorithm is its default
+const CRC_PARAMETERS: [CrcParameters; 5] = [
+    // CRC-16/CCITT-FALSE
+    CrcParameters {
+        algorithm: "crc16",
+        name: "ccitt",
+        poly: 0x1021,
+        init: 0xffff,
+        reflected: false,
+        xor_out: 0x0000,
+    },
+    // CRC-16/XMODEM
+    CrcParameters {
+        algorithm: "crc16",
+        name: "xmodem",
+        poly: 0x1021,
+        init: 0x0000,
+        reflected: false,
+        xor_out: 0x0000,
+    },
+    // CRC-16/KERMIT
+    CrcParameters {
+        algorithm: "crc16",
+        name: "kermit",
+        poly: 0x1021,
+        init: 0x0000,
+        reflected: true,
+        xor_out: 0x0000,
+    },
+    // CRC-32 as used by Ethernet, zlib and PNG
+    CrcParameters {
+        algorithm: "crc32",
+        name: "ieee",
+        poly: 0x04c1_1db7,
+        init: 0xffff_ffff,
+        reflected: true,
+        xor_out: 0xffff_ffff,
+    },
+    // CRC-32C
+    CrcParameters {
+        algorithm: "crc32",
+        name: "castagnoli",
+        poly: 0x1edc_6f41,
+        init: 0xffff_ffff,
+        reflected: true,
+        xor_out: 0xffff_ffff,
+    },
+];
+
+/// Returns true if the identifier names a checksum algorithm
+pub fn is_checksum(algorithm: &str) -> bool {
+    CHECKSUM_ALGORITHMS.contains(&algorithm)
+}
+
+/// Represents a checksum variant: the algorithm, the range of the input it covers and the byte
+/// order of the stored checksum, which immediately follows the covered range.
+#[derive(Clone, Debug)]
+pub struct Checksum {
+    /// One of `CHECKSUM_ALGORITHMS`
+    pub algorithm: Ident,
+    /// The CRC parameters by name, unused by the Fletcher checksums
+    pub parameters: Option<String>,
+    /// The first byte of the input covered by the checksum
+    pub start: syn::Expr,
+    /// The byte after the last byte covered by the checksum, where the stored checksum begins
+    pub end: syn::Expr,
+    /// The byte order of the stored checksum, one of `BYTE_ORDERS`. Big endian when not set
+    pub byte_order: Option<Ident>,
+}
+
+impl Parse for Checksum {
+    /// Parses the algorithm and optionally its parameters, e.g. `crc16 = "kermit"`, followed by
+    /// the comma separated options.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let algorithm: Ident = input.parse()?;
+        let parameters = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let parameters: syn::LitStr = input.parse().map_err(|error| {
+                syn::Error::new(error.span(), "Expected a checksum such as crc32 = \"ieee\"")
+            })?;
+            Some(parameters)
+        } else {
+            None
+        };
+        let parameters = match (algorithm.to_string().as_str(), parameters) {
+            ("fletcher16" | "fletcher32", Some(parameters)) => {
+                return Err(syn::Error::new(
+                    parameters.span(),
+                    format!("{} does not take parameters", algorithm),
+                ))
+            }
+            ("fletcher16" | "fletcher32", None) => None,
+            (crc, Some(parameters)) => {
+                let names = crc_names(crc);
+                if !names.contains(&parameters.value().as_str()) {
+                    return Err(syn::Error::new(
+                        parameters.span(),
+                        format!(
+                            "Expected one of: {}, found {}{}",
+                            names.join(", "),
+                            parameters.value(),
+                            did_you_mean(&parameters.value(), &names)
+                        ),
+                    ));
+                }
+                Some(parameters.value())
+            }
+            (crc, None) => Some(crc_names(crc)[0].to_string()),
+        };
+
+        let mut range = None;
+        let mut byte_order = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            if !CHECKSUM_OPTIONS.contains(&key.to_string().as_str()) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "Unknown checksum option `{}`, expected one of: {}{}",
+                        key,
+                        CHECKSUM_OPTIONS.join(", "),
+                        did_you_mean(&key.to_string(), &CHECKSUM_OPTIONS)
+                    ),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "over" => range = Some(input.parse::<syn::ExprRange>()?),
+                _ => byte_order = Some(one_of(input, &BYTE_ORDERS)?),
+            }
+        }
+
+        let range = range.ok_or_else(|| {
+            syn::Error::new(
+                algorithm.span(),
+                "Checksums require the range of the input they cover, e.g. over = 0..28",
+            )
+        })?;
+        let end = match (range.limits, range.to) {
+            (syn::RangeLimits::HalfOpen(_), Some(end)) => *end,
+            (limits, _) => {
+                return Err(syn::Error::new_spanned(
+                    limits,
+                    "Expected a range with an exclusive end, e.g. over = 0..28",
+                ))
+            }
+        };
+        let start = match range.from {
+            Some(start) => *start,
+            None => syn::parse_quote!(0),
+        };
+        // Bounds written as literals are checked here, other expressions only when parsing
+        if let (Some(first), Some(last)) = (literal_value(&start), literal_value(&end)) {
+            if first >= last {
+                return Err(syn::Error::new_spanned(
+                    quote! { #start..#end },
+                    "The range of a checksum must cover at least one byte, e.g. over = 0..28",
+                ));
+            }
+        }
+
+        Ok(Checksum {
+            algorithm,
+            parameters,
+            start,
+            end,
+            byte_order,
+        })
+    }
+}
+
+impl Checksum {
+    /// The integer type of the checksum, stored in the generated enum
+    pub fn value_type(&self) -> Ident {
+        match self.algorithm.to_string().as_str() {
+            "crc16" | "fletcher16" => Ident::new("u16", Span::call_site()),
+            _ => Ident::new("u32", Span::call_site()),
+        }
+    }
+
+    /// Builds the nom parser expression that reads the stored checksum
+    pub fn stored_parser(&self) -> TokenStream2 {
+        let prefix = match &self.byte_order {
+            Some(order) if order == "le" => "le",
+            _ => "be",
+        };
+        let parser = Ident::new(
+            &format!("{}_{}", prefix, self.value_type()),
+            Span::call_site(),
+        );
+        quote! { ::nom::number::complete::#parser }
+    }
+
+    /// Builds the expression computing the checksum over the `covered: &[u8]` bytes
+    pub fn compute(&self) -> TokenStream2 {
+        let value_type = self.value_type();
+        match self.algorithm.to_string().as_str() {
+            "fletcher16" => quote! {
+                let (mut sum1, mut sum2) = (0u16, 0u16);
+                for byte in covered {
+                    sum1 = (sum1 + *byte as u16) % 255;
+                    sum2 = (sum2 + sum1) % 255;
+                }
+                (sum2 << 8) | sum1
+            },
+            // Sums little endian 16 bit words, padding an odd final byte with zero
+            "fletcher32" => quote! {
+                let (mut sum1, mut sum2) = (0u32, 0u32);
+                for word in covered.chunks(2) {
+                    let word = word[0] as u32 | (*word.get(1).unwrap_or(&0) as u32) << 8;
+                    sum1 = (sum1 + word) % 65535;
+                    sum2 = (sum2 + sum1) % 65535;
+                }
+                (sum2 << 16) | sum1
+            },
+            crc => {
+                let name = self.parameters.as_deref().unwrap_or_default();
+                let parameters = CRC_PARAMETERS
+                    .iter()
+                    .find(|parameters| parameters.algorithm == crc && parameters.name == name)
+                    .expect("CRC parameters are validated when parsed");
+                let width = if crc == "crc16" { 16 } else { 32 };
+                let literal = |value: u32| match width {
+                    16 => Literal::u16_suffixed(value as u16),
+                    _ => Literal::u32_suffixed(value),
+                };
+                let (init, xor_out) = (literal(parameters.init), literal(parameters.xor_out));
+                if parameters.reflected {
+                    let reflected_poly = literal(parameters.poly.reverse_bits() >> (32 - width));
+                    quote! {
+                        let mut crc: #value_type = #init;
+                        for byte in covered {
+                            crc ^= *byte as #value_type;
+                            for _ in 0..8 {
+                                crc = if crc & 1 == 1 { (crc >> 1) ^ #reflected_poly } else { crc >> 1 };
+                            }
+                        }
+                        crc ^ #xor_out
+                    }
+                } else {
+                    let poly = literal(parameters.poly);
+                    let shift = Literal::u32_unsuffixed(width - 8);
+                    let top_bit = Literal::u32_unsuffixed(width - 1);
+                    quote! {
+                        let mut crc: #value_type = #init;
+                        for byte in covered {
+                            crc ^= (*byte as #value_type) << #shift;
+                            for _ in 0..8 {
+                                crc = if crc & (1 << #top_bit) != 0 { (crc << 1) ^ #poly } else { crc << 1 };
+                            }
+                        }
+                        crc ^ #xor_out
+                    }
+                }
+            }
+        }
+    }
+}
+
+// The value of an integer literal, e.g. a bound of `over = 0..28`
+fn literal_value(expr: &syn::Expr) -> Option<u64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+// Returns the parameter names available for the CRC, the default first
+fn crc_names(crc: &str) -> Vec<&'static str> {
+    CRC_PARAMETERS
+        .iter()
+        .filter(|parameters| parameters.algorithm == crc)
+        .map(|parameters| parameters.name)
+        .collect()
+}
@@ -0,0 +1,337 @@
+// element.rs
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::{
+    legacy_float::{is_legacy_float, legacy_float_parser, legacy_float_types},
+    options::CastOptions,
+    suggest::did_you_mean,
+};
+
+/// The primitive types nom provides `le_*`/`be_*` parsers for
+pub const PRIMITIVE_TYPES: [&str; 10] = [
+    "f32", "f64", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64",
+];
+
+/// The variant kinds that are read without a conversion, e.g. `#[cast(uuid)]`
+pub const KINDS: [&str; 5] = ["uuid", "guid_le", "ipv4", "ipv6", "mac"];
+
+/// The variant kinds of `KINDS` whose feature is enabled, which are the ones worth suggesting
+pub fn enabled_kinds() -> Vec<&'static str> {
+    KINDS
+        .iter()
+        .copied()
+        .filter(|kind| !matches!(*kind, "uuid" | "guid_le") || cfg!(feature = "uuid"))
+        .filter(|kind| *kind != "mac" || cfg!(feature = "macaddr"))
+        .collect()
+}
+
+/// Represents a single element decoded from the input, either the whole value of a scalar variant
+/// or one entry of an array variant.
+#[derive(Clone, Debug)]
+pub enum ElementType {
+    /// A primitive type decoded directly by nom, e.g. `f32`
+    Primitive(Ident),
+    /// A `bool`, only valid as the element of a bitmask array
+    Bool(Ident),
+    /// A `num_complex::Complex` whose real and imaginary parts are the given float primitive,
+    /// stored real part first
+    Complex(Ident),
+    /// A `uuid::Uuid` read from 16 bytes, either in RFC 4122 byte order (`uuid`) or in the
+    /// Microsoft GUID layout whose first three fields are little endian (`guid_le`)
+    Uuid(Ident),
+    /// A network address read in network byte order: `std::net::Ipv4Addr` (`ipv4`),
+    /// `std::net::Ipv6Addr` (`ipv6`) or `macaddr::MacAddr6` (`mac`)
+    Address(Ident),
+}
+
+impl ElementType {
+    /// Resolves a variant kind that is written without a conversion, e.g. `#[cast(uuid)]`.
+    pub fn from_kind(kind: &Ident) -> Result<Self, syn::Error> {
+        match kind.to_string().as_str() {
+            "uuid" | "guid_le" if !cfg!(feature = "uuid") => Err(syn::Error::new(
+                kind.span(),
+                format!("{} requires the `uuid` feature of binary_type_cast", kind),
+            )),
+            "uuid" | "guid_le" => Ok(ElementType::Uuid(kind.clone())),
+            "mac" if !cfg!(feature = "macaddr") => Err(syn::Error::new(
+                kind.span(),
+                "mac requires the `macaddr` feature of binary_type_cast",
+            )),
+            "ipv4" | "ipv6" | "mac" => Ok(ElementType::Address(kind.clone())),
+            k => Err(syn::Error::new(
+                kind.span(),
+                format!("Expected valid conversion or valid cast type, found {}", k),
+            )),
+        }
+    }
+
+    /// Returns true if the element is a signed or unsigned integer primitive
+    pub fn is_integer(&self) -> bool {
+        matches!(self, ElementType::Primitive(primitive) if primitive.to_string().starts_with(['i', 'u']))
+    }
+
+    /// Returns true if the element is a floating point primitive
+    pub fn is_float(&self) -> bool {
+        matches!(self, ElementType::Primitive(primitive) if primitive.to_string().starts_with('f'))
+    }
+
+    /// Returns true if the element is a signed integer primitive
+    pub fn is_signed_integer(&self) -> bool {
+        matches!(self, ElementType::Primitive(primitive) if primitive.to_string().starts_with('i'))
+    }
+
+    /// Checks that the element can be decoded with the given conversion
+    pub fn check_conversion(&self, conversion: &Ident) -> Result<(), syn::Error> {
+        if let (ElementType::Bool(ident), true) = (self, conversion == "me_") {
+            return Err(syn::Error::new(
+                ident.span(),
+                "bitmasks can only be read with from_le_bytes or from_be_bytes",
+            ));
+        }
+        let conversion = conversion.to_string();
+        if !is_legacy_float(&conversion) {
+            return Ok(());
+        }
+        let supported = legacy_float_types(&conversion);
+        match self {
+            ElementType::Primitive(primitive)
+                if supported.contains(&primitive.to_string().as_str()) =>
+            {
+                Ok(())
+            }
+            ElementType::Primitive(ident)
+            | ElementType::Bool(ident)
+            | ElementType::Complex(ident)
+            | ElementType::Uuid(ident)
+            | ElementType::Address(ident) => Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "{} can only be cast to {}",
+                    conversion,
+                    supported.join(" or ")
+                ),
+            )),
+        }
+    }
+
+    /// Builds the nom parser expression that decodes one element with the given conversion,
+    /// either a byte order prefix such as `le_`, `be_` or `me_` or one of the legacy float formats.
+    pub fn parser(&self, conversion: &Ident) -> TokenStream2 {
+        match self {
+            ElementType::Primitive(primitive) if is_legacy_float(&conversion.to_string()) => {
+                legacy_float_parser(&conversion.to_string(), &primitive.to_string())
+            }
+            ElementType::Primitive(primitive) => primitive_parser(conversion, primitive),
+            // Bits are expanded by `CastType::array_parser`, which never calls this
+            ElementType::Bool(_) => unreachable!("bool elements are only decoded from bitmasks"),
+            ElementType::Complex(component) => {
+                let parser = primitive_parser(conversion, component);
+                quote! {
+                    ::nom::combinator::map(
+                        ::nom::sequence::pair(#parser, #parser),
+                        |(re, im)| ::num_complex::Complex::new(re, im),
+                    )
+                }
+            }
+            ElementType::Uuid(kind) if kind == "guid_le" => quote! {
+                ::nom::combinator::map(
+                    ::nom::sequence::tuple((
+                        ::nom::number::complete::le_u32,
+                        ::nom::number::complete::le_u16,
+                        ::nom::number::complete::le_u16,
+                        ::nom::number::complete::be_u64,
+                    )),
+                    |(d1, d2, d3, d4)| ::uuid::Uuid::from_fields(d1, d2, d3, &d4.to_be_bytes()),
+                )
+            },
+            ElementType::Uuid(_) => quote! {
+                ::nom::combinator::map(::nom::number::complete::be_u128, ::uuid::Uuid::from_u128)
+            },
+            ElementType::Address(kind) if kind == "ipv4" => quote! {
+                ::nom::combinator::map(::nom::number::complete::be_u32, ::std::net::Ipv4Addr::from)
+            },
+            ElementType::Address(kind) if kind == "ipv6" => quote! {
+                ::nom::combinator::map(::nom::number::complete::be_u128, ::std::net::Ipv6Addr::from)
+            },
+            ElementType::Address(_) => quote! {
+                ::nom::combinator::map(::nom::bytes::complete::take(6usize), |bytes: &[u8]| {
+                    ::macaddr::MacAddr6::new(bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5])
+                })
+            },
+        }
+    }
+}
+
+impl Parse for ElementType {
+    /// Parses a single element, e.g. `f32` or `Complex<f64>`.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input
+            .parse()
+            .map_err(|error| syn::Error::new(error.span(), "Expected a cast type such as f32"))?;
+        match ident.to_string().as_str() {
+            primitive if PRIMITIVE_TYPES.contains(&primitive) => Ok(ElementType::Primitive(ident)),
+            "bool" => Ok(ElementType::Bool(ident)),
+            "Complex" => {
+                if !cfg!(feature = "num-complex") {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "Complex cast types require the `num-complex` feature of binary_type_cast",
+                    ));
+                }
+                input.parse::<Token![<]>()?;
+                let component: Ident = input.parse()?;
+                input.parse::<Token![>]>()?;
+                match component.to_string().as_str() {
+                    "f32" | "f64" => Ok(ElementType::Complex(component)),
+                    c => Err(syn::Error::new(
+                        component.span(),
+                        format!(
+                            "Expected f32 or f64 as the component type of Complex, found {}",
+                            c
+                        ),
+                    )),
+                }
+            }
+            // Kinds that are read on their own cannot follow a conversion
+            found if found == "String" || KINDS.contains(&found) => Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "Expected valid conversion or valid cast type, found {}\nnote: {} is read without a conversion or byte order, e.g. #[cast({})]",
+                    found, found, found
+                ),
+            )),
+            found => {
+                // Complex is only suggested when the `num-complex` feature makes it usable
+                let mut cast_types = PRIMITIVE_TYPES.to_vec();
+                cast_types.push("bool");
+                let mut accepted = PRIMITIVE_TYPES.join(", ");
+                if cfg!(feature = "num-complex") {
+                    cast_types.push("Complex");
+                    accepted.push_str(", Complex<f32>, Complex<f64>");
+                }
+                Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "Expected valid conversion or valid cast type, found {}{}\nnote: the accepted cast types are {} and arrays of them such as [f32; 4]",
+                        found,
+                        did_you_mean(found, &cast_types),
+                        accepted
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+impl ToTokens for ElementType {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            ElementType::Primitive(primitive) | ElementType::Bool(primitive) => {
+                primitive.to_tokens(tokens)
+            }
+            ElementType::Complex(component) => {
+                tokens.extend(quote! { ::num_complex::Complex<#component> })
+            }
+            ElementType::Uuid(_) => tokens.extend(quote! { ::uuid::Uuid }),
+            ElementType::Address(kind) if kind == "ipv4" => {
+                tokens.extend(quote! { ::std::net::Ipv4Addr })
+            }
+            ElementType::Address(kind) if kind == "ipv6" => {
+                tokens.extend(quote! { ::std::net::Ipv6Addr })
+            }
+            ElementType::Address(_) => tokens.extend(quote! { ::macaddr::MacAddr6 }),
+        }
+    }
+}
+
+/// Represents the cast type of a scalar variant or the element type of an array variant: the
+/// decoded element together with the options that post-process it.
+#[derive(Clone, Debug)]
+pub struct CastType {
+    pub element: ElementType,
+    pub options: CastOptions,
+}
+
+impl CastType {
+    /// Builds the nom parser expression producing the value stored in the generated enum
+    pub fn parser(&self, conversion: &Ident) -> TokenStream2 {
+        self.options
+            .wrap_parser(&self.element, self.element.parser(conversion))
+    }
+
+    /// Builds the nom parser expression producing a `Vec` of the `length` array elements, either
+    /// by decoding each element in turn or by expanding the bits of a bitmask. The length may be
+    /// any const expression, e.g. `2 * LANES`
+    pub fn array_parser(&self, conversion: &Ident, length: &syn::Expr) -> TokenStream2 {
+        if !self.options.bitmask {
+            let parser = self.parser(conversion);
+            return quote! { ::nom::multi::count(#parser, #length) };
+        }
+        // The bytes are read as one unsigned word in the declared byte order, whose bits are
+        // counted from the least significant end unless the bit order is `msb_first`
+        let position = match &self.options.bit_order {
+            Some(bit_order) if bit_order == "msb_first" => quote! { bytes.len() * 8 - 1 - bit },
+            _ => quote! { bit },
+        };
+        let byte = if conversion == "be_" {
+            quote! { bytes.len() - 1 - position / 8 }
+        } else {
+            quote! { position / 8 }
+        };
+        quote! {
+            ::nom::combinator::map(
+                ::nom::bytes::complete::take(usize::div_ceil(#length, 8)),
+                |bytes: &[u8]| -> ::std::vec::Vec<bool> {
+                    (0..(#length))
+                        .map(|bit: usize| {
+                            let position = #position;
+                            (bytes[#byte] >> (position % 8)) & 1 == 1
+                        })
+                        .collect()
+                },
+            )
+        }
+    }
+}
+
+impl ToTokens for CastType {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        self.options.value_type(&self.element, tokens)
+    }
+}
+
+// Builds the nom parser expression for a primitive. Little and big endian primitives are read
+// directly by nom, e.g. `le_f32`, while middle endian primitives are read as little endian 16 bit
+// words stored most significant word first
+fn primitive_parser(conversion: &Ident, primitive: &Ident) -> TokenStream2 {
+    if conversion != "me_" {
+        let parser = Ident::new(&format!("{conversion}{primitive}"), Span::call_site());
+        return quote! { ::nom::number::complete::#parser };
+    }
+    match primitive.to_string().as_str() {
+        "u32" | "i32" | "f32" => quote! {
+            ::nom::combinator::map(::nom::number::complete::le_u32, |raw: u32| {
+                <#primitive>::from_ne_bytes(raw.rotate_left(16).to_ne_bytes())
+            })
+        },
+        "u64" | "i64" | "f64" => quote! {
+            ::nom::combinator::map(::nom::number::complete::le_u64, |raw: u64| {
+                let bits = ((raw & 0xffff) << 48)
+                    | (((raw >> 16) & 0xffff) << 32)
+                    | (((raw >> 32) & 0xffff) << 16)
+                    | (raw >> 48);
+                <#primitive>::from_ne_bytes(bits.to_ne_bytes())
+            })
+        },
+        // A single word has no word order to swap
+        _ => {
+            let parser = Ident::new(&format!("le_{primitive}"), Span::call_site());
+            quote! { ::nom::number::complete::#parser }
+        }
+    }
+}
@@ -3,8 +3,10 @@ use quote::quote;
 
 use super::CastTypeData;
 
+// The snippets below are not compiled as doctests: they are shown without the imports of the crate
+// that derives TypeCast, and the expansion refers to the enum of the first snippet
 /// # Example
-/// ```rust
+/// ```rust,ignore
 ///  #[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
 ///  pub enum DataTypes {
 ///      // 4 bytes
@@ -36,7 +38,7 @@ use super::CastTypeData;
 ///  }
 ///  ```
 ///
-/// ```rust
+/// ```rust,ignore
 /// // Generated at compile time for the FromStr trait:
 /// impl std::str::FromStr for DataTypes {
 ///     type Err = Box<dyn std::error::Error + Send + Sync>;
@@ -58,7 +60,7 @@ use super::CastTypeData;
 /// }
 /// ```
 ///
-/// ```rust
+/// ```rust,ignore
 /// // Usage
 ///
 /// pub fn usage_example() {
@@ -81,36 +83,59 @@ use super::CastTypeData;
 /// }
 ///
 /// ```
-
 pub fn generated_from_str_impl(name: &Ident, cast_type_data: &mut CastTypeData) -> TokenStream2 {
     let mut match_arms = quote! {};
     for variant in &cast_type_data.variants {
         match_arms = quote! {
             #match_arms
-            stringify!(#variant) => Ok(#name::#variant),
+            ::std::stringify!(#variant) => ::std::result::Result::Ok(#name::#variant),
         };
     }
     for complex_variant in &cast_type_data.complex_variants {
         match_arms = quote! {
             #match_arms
-            stringify!(#complex_variant) => Ok(#name::#complex_variant),
+            ::std::stringify!(#complex_variant) => ::std::result::Result::Ok(#name::#complex_variant),
         };
     }
     for string_variant in &cast_type_data.string_variants {
         match_arms = quote! {
             #match_arms
-            stringify!(#string_variant) => Ok(#name::#string_variant),
+            ::std::stringify!(#string_variant) => ::std::result::Result::Ok(#name::#string_variant),
+        };
+    }
+    for checksum_variant in &cast_type_data.checksum_variants {
+        match_arms = quote! {
+            #match_arms
+            ::std::stringify!(#checksum_variant) => ::std::result::Result::Ok(#name::#checksum_variant),
+        };
+    }
+    for padding_variant in &cast_type_data.padding_variants {
+        match_arms = quote! {
+            #match_arms
+            ::std::stringify!(#padding_variant) => ::std::result::Result::Ok(#name::#padding_variant),
+        };
+    }
+    for magic_variant in &cast_type_data.magic_variants {
+        match_arms = quote! {
+            #match_arms
+            ::std::stringify!(#magic_variant) => ::std::result::Result::Ok(#name::#magic_variant),
+        };
+    }
+    for ignored_variant in &cast_type_data.ignored_variants {
+        match_arms = quote! {
+            #match_arms
+            ::std::stringify!(#ignored_variant) => ::std::result::Result::Ok(#name::#ignored_variant),
         };
     }
 
     quote! {
-        impl std::str::FromStr for #name {
-            type Err = Box<dyn std::error::Error>;
+        impl ::std::str::FromStr for #name {
+            type Err = ::std::boxed::Box<dyn ::std::error::Error>;
 
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
                 match s {
                     #match_arms
-                    _ => Err("Invalid variant".into())
+                    _ => ::std::result::Result::Err("Invalid variant".into())
                 }
             }
         }
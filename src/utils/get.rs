@@ -0,0 +1,50 @@
+// get.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+
+/// Generates the `{Cast}Get<T>` trait next to the generated enum, with `get` borrowing and `take`
+/// consuming the value of type `T`, and implements it for every cast type. Unlike `TryInto`, the
+/// value can be borrowed and code generic over the cast type can be written, e.g.
+/// `fn field<T>(&self, name: &str) -> Option<&T> where DataTypesCast: DataTypesCastGet<T>`.
+pub fn generate_get_trait(
+    type_variants_map: &HashMap<String, Vec<Ident>>,
+    data_kind_name: &Ident,
+    vis: &syn::Visibility,
+) -> TokenStream2 {
+    let trait_name = format_ident!("{}Get", data_kind_name);
+    let mut get_impls = Vec::new();
+    for (cast_type_str, variants) in type_variants_map {
+        let cast_type: TokenStream2 = cast_type_str.parse().unwrap();
+        get_impls.push(quote! {
+            impl #trait_name<#cast_type> for #data_kind_name {
+                fn get(&self) -> ::std::option::Option<&#cast_type> {
+                    match self {
+                        #(#data_kind_name::#variants(val) => ::std::option::Option::Some(val),)*
+                        #[allow(unreachable_patterns)]
+                        _ => ::std::option::Option::None,
+                    }
+                }
+
+                fn take(self) -> ::std::result::Result<#cast_type, Self> {
+                    match self {
+                        #(#data_kind_name::#variants(val) => ::std::result::Result::Ok(val),)*
+                        #[allow(unreachable_patterns)]
+                        other => ::std::result::Result::Err(other),
+                    }
+                }
+            }
+        });
+    }
+    quote! {
+        // Extracts a value of type `T` from the generated enum, when one of the variants holding `T` is set
+        #vis trait #trait_name<T>: ::std::marker::Sized {
+            // Borrows the value, or returns None for a variant holding another type
+            fn get(&self) -> ::std::option::Option<&T>;
+            // Takes the value, or returns the enum unchanged for a variant holding another type
+            fn take(self) -> ::std::result::Result<T, Self>;
+        }
+
+        #(#get_impls)*
+    }
+}
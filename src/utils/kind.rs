@@ -0,0 +1,104 @@
+// kind.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+
+use super::CastTypeData;
+
+/// Generates `kind` and `type_name` for the generated enum, which map a decoded value back to the
+/// variant of the enum decorated with #[derive(TypeCast)] that produced it and name the type of
+/// the value it holds, e.g. `"f32"`, `"[u16; 4]"` or `"Ipv4Addr"`. Variants without a value are named `"()"`.
+pub fn generate_kind_impl(
+    name: &Ident,
+    data_kind_name: &Ident,
+    vis: &syn::Visibility,
+    cast_type_data: &CastTypeData,
+) -> TokenStream2 {
+    let mut valued: Vec<(&Ident, String)> = Vec::new();
+    for (variant, cast_type) in cast_type_data
+        .variants
+        .iter()
+        .zip(&cast_type_data.cast_types)
+    {
+        valued.push((variant, type_name(cast_type)));
+    }
+    for (variant, group) in cast_type_data
+        .complex_variants
+        .iter()
+        .zip(&cast_type_data.complex_cast_types_group)
+    {
+        valued.push((variant, type_name(group)));
+    }
+    for (variant, string_type) in cast_type_data
+        .string_variants
+        .iter()
+        .zip(&cast_type_data.string_types)
+    {
+        valued.push((variant, string_type.to_string()));
+    }
+    for (variant, checksum) in cast_type_data
+        .checksum_variants
+        .iter()
+        .zip(&cast_type_data.checksums)
+    {
+        valued.push((variant, checksum.value_type().to_string()));
+    }
+    let unit: Vec<&Ident> = cast_type_data
+        .padding_variants
+        .iter()
+        .chain(&cast_type_data.magic_variants)
+        .collect();
+
+    let (valued_variants, valued_type_names): (Vec<&Ident>, Vec<String>) =
+        valued.into_iter().unzip();
+    let valued_names = valued_variants.iter().map(|_| name);
+    let unit_names = unit.iter().map(|_| name);
+
+    quote! {
+        impl #data_kind_name {
+            // The variant of the enum decorated with #[derive(TypeCast)] whose parse produced this value
+            #vis fn kind(&self) -> #name {
+                match *self {
+                    #(#data_kind_name::#valued_variants(..) => #valued_names::#valued_variants,)*
+                    #(#data_kind_name::#unit => #unit_names::#unit,)*
+                }
+            }
+
+            // The type of the value held by the variant, without the paths the macro writes in front of it
+            #vis fn type_name(&self) -> &'static str {
+                match *self {
+                    #(#data_kind_name::#valued_variants(..) => #valued_type_names,)*
+                    #(#data_kind_name::#unit => "()",)*
+                }
+            }
+        }
+    }
+}
+
+/// The paths the generated code writes in front of the types it names, which are not part of the
+/// type as written in the cast attribute
+const GENERATED_PATHS: [&str; 6] = [
+    "::std::option::",
+    "::std::string::",
+    "::std::net::",
+    "::num_complex::",
+    "::uuid::",
+    "::macaddr::",
+];
+
+// Renders the type as it would be written by hand, e.g. `[u16; 4]` rather than `[u16 ; 4]`, and
+// `Complex<f32>` rather than `::num_complex::Complex<f32>`
+pub fn type_name(cast_type: &impl ToTokens) -> String {
+    let mut name = cast_type
+        .to_token_stream()
+        .to_string()
+        .replace(" ;", ";")
+        .replace(":: ", "::")
+        .replace(" ::", "::")
+        .replace("< ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">");
+    for path in GENERATED_PATHS {
+        name = name.replace(path, "");
+    }
+    name
+}
@@ -0,0 +1,133 @@
+// legacy_float.rs
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// The legacy floating point conversions paired with the cast types they decode into. The cast
+/// type selects the width of the encoded value, e.g. `ibm_float => f32` reads a 4 byte IBM single
+/// while `ibm_float => f64` reads an 8 byte IBM double.
+pub const LEGACY_FLOAT_CONVERSIONS: [(&str, &str); 7] = [
+    ("ibm_float", "f32"),
+    ("ibm_float", "f64"),
+    ("vax_f", "f32"),
+    ("vax_d", "f64"),
+    ("vax_g", "f64"),
+    ("mil1750a_32", "f32"),
+    ("mil1750a_48", "f64"),
+];
+
+/// Returns true if the conversion is one of the legacy floating point formats
+pub fn is_legacy_float(conversion: &str) -> bool {
+    LEGACY_FLOAT_CONVERSIONS
+        .iter()
+        .any(|(legacy, _)| *legacy == conversion)
+}
+
+/// Returns the cast types the given legacy conversion can decode into
+pub fn legacy_float_types(conversion: &str) -> Vec<&'static str> {
+    LEGACY_FLOAT_CONVERSIONS
+        .iter()
+        .filter(|(legacy, _)| *legacy == conversion)
+        .map(|(_, cast_type)| *cast_type)
+        .collect()
+}
+
+/// Builds the nom parser expression that decodes a legacy floating point value into the IEEE 754
+/// `cast_type`. The combination must be listed in `LEGACY_FLOAT_CONVERSIONS`.
+pub fn legacy_float_parser(conversion: &str, cast_type: &str) -> TokenStream2 {
+    match (conversion, cast_type) {
+        // IBM System/360 hexadecimal float: sign bit, 7 bit base 16 exponent biased by 64 and a
+        // 24 bit fraction, big endian. The value is 0.fraction * 16^(exponent - 64)
+        ("ibm_float", "f32") => quote! {
+            ::nom::combinator::map(::nom::number::complete::be_u32, |bits: u32| -> f32 {
+                let sign = if bits >> 31 == 1 { -1.0 } else { 1.0 };
+                let exponent = ((bits >> 24) & 0x7f) as i32;
+                let fraction = (bits & 0x00ff_ffff) as f64;
+                (sign * fraction * f64::powi(2.0, 4 * (exponent - 64) - 24)) as f32
+            })
+        },
+        // IBM System/360 hexadecimal double: as the single but with a 56 bit fraction
+        ("ibm_float", "f64") => quote! {
+            ::nom::combinator::map(::nom::number::complete::be_u64, |bits: u64| -> f64 {
+                let sign = if bits >> 63 == 1 { -1.0 } else { 1.0 };
+                let exponent = ((bits >> 56) & 0x7f) as i32;
+                let fraction = (bits & 0x00ff_ffff_ffff_ffff) as f64;
+                sign * fraction * f64::powi(2.0, 4 * (exponent - 64) - 56)
+            })
+        },
+        // VAX F_floating: two little endian 16 bit words, most significant word first. Sign bit,
+        // 8 bit exponent biased by 128 and a 23 bit fraction with a hidden leading bit. The value
+        // is 0.1fraction * 2^(exponent - 128). A zero exponent with the sign bit set is a reserved
+        // operand and decodes to NaN
+        ("vax_f", "f32") => quote! {
+            ::nom::combinator::map(::nom::number::complete::le_u32, |raw: u32| -> f32 {
+                let bits = raw.rotate_left(16);
+                let exponent = ((bits >> 23) & 0xff) as i32;
+                if exponent == 0 {
+                    return if bits >> 31 == 1 { f32::NAN } else { 0.0 };
+                }
+                let sign = if bits >> 31 == 1 { -1.0 } else { 1.0 };
+                let mantissa = ((1 << 23) | (bits & 0x007f_ffff)) as f64;
+                (sign * mantissa * f64::powi(2.0, exponent - 128 - 24)) as f32
+            })
+        },
+        // VAX D_floating: four little endian 16 bit words, most significant word first. Sign bit,
+        // 8 bit exponent biased by 128 and a 55 bit fraction with a hidden leading bit
+        ("vax_d", "f64") => quote! {
+            ::nom::combinator::map(::nom::number::complete::le_u64, |raw: u64| -> f64 {
+                let bits = ((raw & 0xffff) << 48)
+                    | (((raw >> 16) & 0xffff) << 32)
+                    | (((raw >> 32) & 0xffff) << 16)
+                    | (raw >> 48);
+                let exponent = ((bits >> 55) & 0xff) as i32;
+                if exponent == 0 {
+                    return if bits >> 63 == 1 { f64::NAN } else { 0.0 };
+                }
+                let sign = if bits >> 63 == 1 { -1.0 } else { 1.0 };
+                let mantissa = ((1 << 55) | (bits & 0x007f_ffff_ffff_ffff)) as f64;
+                sign * mantissa * f64::powi(2.0, exponent - 128 - 56)
+            })
+        },
+        // VAX G_floating: four little endian 16 bit words, most significant word first. Sign bit,
+        // 11 bit exponent biased by 1024 and a 52 bit fraction with a hidden leading bit
+        ("vax_g", "f64") => quote! {
+            ::nom::combinator::map(::nom::number::complete::le_u64, |raw: u64| -> f64 {
+                let bits = ((raw & 0xffff) << 48)
+                    | (((raw >> 16) & 0xffff) << 32)
+                    | (((raw >> 32) & 0xffff) << 16)
+                    | (raw >> 48);
+                let exponent = ((bits >> 52) & 0x7ff) as i32;
+                if exponent == 0 {
+                    return if bits >> 63 == 1 { f64::NAN } else { 0.0 };
+                }
+                let sign = if bits >> 63 == 1 { -1.0 } else { 1.0 };
+                let mantissa = ((1 << 52) | (bits & 0x000f_ffff_ffff_ffff)) as f64;
+                // Scale in two steps so the smallest exponents do not underflow the power of two
+                sign * (mantissa * f64::powi(2.0, -53)) * f64::powi(2.0, exponent - 1024)
+            })
+        },
+        // MIL-STD-1750A single precision: big endian 24 bit two's complement fraction followed by
+        // an 8 bit two's complement exponent. The value is fraction / 2^23 * 2^exponent
+        ("mil1750a_32", "f32") => quote! {
+            ::nom::combinator::map(::nom::number::complete::be_u32, |bits: u32| -> f32 {
+                let mantissa = ((bits as i32) >> 8) as f64;
+                let exponent = (bits & 0xff) as u8 as i8 as i32;
+                (mantissa * f64::powi(2.0, exponent - 23)) as f32
+            })
+        },
+        // MIL-STD-1750A extended precision: the single precision layout followed by 16 more
+        // fraction bits, giving a 40 bit two's complement fraction
+        ("mil1750a_48", "f64") => quote! {
+            ::nom::combinator::map(::nom::bytes::complete::take(6usize), |bytes: &[u8]| -> f64 {
+                let high = i32::from_be_bytes([bytes[0], bytes[1], bytes[2], 0]) >> 8;
+                let exponent = bytes[3] as i8 as i32;
+                let low = u16::from_be_bytes([bytes[4], bytes[5]]) as i64;
+                let mantissa = (((high as i64) << 16) | low) as f64;
+                mantissa * f64::powi(2.0, exponent - 39)
+            })
+        },
+        (conversion, cast_type) => unreachable!(
+            "{} => {} is not a supported legacy float conversion",
+            conversion, cast_type
+        ),
+    }
+}
@@ -0,0 +1,45 @@
+// magic.rs
+use proc_macro2::{Ident, Literal, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+/// Represents a signature the input must start with, e.g. `#[cast(magic = b"\x89HDF")]`
+#[derive(Clone, Debug)]
+pub struct Magic {
+    pub signature: syn::LitByteStr,
+}
+
+impl Parse for Magic {
+    /// Parses `magic = ` followed by a byte string literal.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = input.parse()?;
+        if !input.peek(Token![=]) {
+            return Err(syn::Error::new(
+                keyword.span(),
+                "Expected a signature such as magic = b\"PDS4\"",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        let signature: syn::LitByteStr = input.parse().map_err(|error| {
+            syn::Error::new(error.span(), "Expected a byte string such as b\"PDS4\"")
+        })?;
+        if signature.value().is_empty() {
+            return Err(syn::Error::new(
+                signature.span(),
+                "The signature must not be empty",
+            ));
+        }
+        Ok(Magic { signature })
+    }
+}
+
+impl Magic {
+    /// Builds the parser expression that reads as many bytes as the signature holds
+    pub fn parser(&self) -> TokenStream2 {
+        let length = Literal::usize_suffixed(self.signature.value().len());
+        quote! { ::nom::bytes::complete::take(#length) }
+    }
+}
@@ -1,18 +1,42 @@
+pub mod accessors;
 pub mod cast_extraction;
+pub mod checksum;
+pub mod element;
 pub mod from_str;
-pub mod try_into;
+pub mod get;
+pub mod kind;
+pub mod legacy_float;
+pub mod magic;
+pub mod numeric;
+pub mod options;
+pub mod padding;
+pub mod suggest;
+pub mod try_from;
+pub mod type_cast_options;
+
+use checksum::Checksum;
+use element::CastType;
+use magic::Magic;
+use padding::Padding;
 
 // Define a struct to hold the vectors
 #[derive(Clone, Debug, Default)]
 pub struct CastTypeData {
-    pub cast_types: Vec<proc_macro2::Ident>,
-    pub complex_cast_types: Vec<proc_macro2::Ident>,
+    pub cast_types: Vec<CastType>,
+    pub complex_cast_types: Vec<CastType>,
     pub complex_cast_types_group: Vec<proc_macro2::Group>,
     pub conversion: Vec<proc_macro2::Ident>,
     pub complex_conversion: Vec<proc_macro2::Ident>,
-    pub number_of_array_elements: Vec<proc_macro2::Literal>,
+    pub number_of_array_elements: Vec<syn::Expr>,
     pub variants: Vec<proc_macro2::Ident>,
     pub complex_variants: Vec<proc_macro2::Ident>,
     pub string_types: Vec<proc_macro2::Ident>,
     pub string_variants: Vec<proc_macro2::Ident>,
+    pub checksums: Vec<Checksum>,
+    pub checksum_variants: Vec<proc_macro2::Ident>,
+    pub paddings: Vec<Padding>,
+    pub padding_variants: Vec<proc_macro2::Ident>,
+    pub magics: Vec<Magic>,
+    pub magic_variants: Vec<proc_macro2::Ident>,
+    pub ignored_variants: Vec<proc_macro2::Ident>,
 }
@@ -0,0 +1,154 @@
+// numeric.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+
+use super::{element::ElementType, options::CastOptions, CastTypeData};
+
+/// Generates the widening conversions of the generated enum, covering every variant holding an
+/// integer or a float, or an array of them:
+///
+/// * `to_f64(&self) -> Option<f64>` for scalars. `u64` and `i64` values above 2^53 are rounded to
+///   the nearest `f64`, every other type converts exactly
+/// * `to_i128(&self) -> Option<i128>` for integer scalars, which always converts exactly
+/// * `to_f64_vec(&self) -> Option<Vec<f64>>` for scalars and arrays, rounding like `to_f64`
+///
+/// Values marked `missing` or `invalid` are `None` from `to_f64` and `to_i128`, and `NaN` in
+/// `to_f64_vec`. Variants holding any other type, including values converted with `into` or
+/// `flags`, return `None`.
+pub fn generate_numeric_impl(
+    data_kind_name: &Ident,
+    vis: &syn::Visibility,
+    cast_type_data: &CastTypeData,
+) -> TokenStream2 {
+    let mut to_f64_arms = Vec::new();
+    let mut to_i128_arms = Vec::new();
+    let mut to_f64_vec_arms = Vec::new();
+
+    for (variant, cast_type) in cast_type_data
+        .variants
+        .iter()
+        .zip(&cast_type_data.cast_types)
+    {
+        let Some(primitive) = numeric_primitive(&cast_type.element, &cast_type.options) else {
+            continue;
+        };
+        let as_f64 = widen_to_f64(&primitive, quote! { val });
+        if has_sentinel(&cast_type.options) {
+            to_f64_arms.push(quote! {
+                #data_kind_name::#variant(val) => (*val).map(|val| #as_f64),
+            });
+            to_f64_vec_arms.push(quote! {
+                #data_kind_name::#variant(val) => ::std::option::Option::Some(::std::vec![(*val).map_or(f64::NAN, |val| #as_f64)]),
+            });
+            if cast_type.element.is_integer() {
+                to_i128_arms.push(quote! {
+                    #data_kind_name::#variant(val) => (*val).map(i128::from),
+                });
+            }
+        } else {
+            let as_f64 = widen_to_f64(&primitive, quote! { *val });
+            to_f64_arms.push(quote! {
+                #data_kind_name::#variant(val) => ::std::option::Option::Some(#as_f64),
+            });
+            to_f64_vec_arms.push(quote! {
+                #data_kind_name::#variant(val) => ::std::option::Option::Some(::std::vec![#as_f64]),
+            });
+            if cast_type.element.is_integer() {
+                to_i128_arms.push(quote! {
+                    #data_kind_name::#variant(val) => ::std::option::Option::Some(i128::from(*val)),
+                });
+            }
+        }
+    }
+
+    for (variant, cast_type) in cast_type_data
+        .complex_variants
+        .iter()
+        .zip(&cast_type_data.complex_cast_types)
+    {
+        let Some(primitive) = numeric_primitive(&cast_type.element, &cast_type.options) else {
+            continue;
+        };
+        let element = if has_sentinel(&cast_type.options) {
+            let as_f64 = widen_to_f64(&primitive, quote! { val });
+            quote! { val.map_or(f64::NAN, |val| #as_f64) }
+        } else {
+            widen_to_f64(&primitive, quote! { *val })
+        };
+        to_f64_vec_arms.push(quote! {
+            #data_kind_name::#variant(val) => ::std::option::Option::Some(val.iter().map(|val| #element).collect()),
+        });
+    }
+
+    // Checksums hold the stored u16 or u32
+    for (variant, checksum) in cast_type_data
+        .checksum_variants
+        .iter()
+        .zip(&cast_type_data.checksums)
+    {
+        let as_f64 = widen_to_f64(&checksum.value_type().to_string(), quote! { *val });
+        to_f64_arms.push(quote! {
+            #data_kind_name::#variant(val) => ::std::option::Option::Some(#as_f64),
+        });
+        to_f64_vec_arms.push(quote! {
+            #data_kind_name::#variant(val) => ::std::option::Option::Some(::std::vec![#as_f64]),
+        });
+        to_i128_arms.push(quote! {
+            #data_kind_name::#variant(val) => ::std::option::Option::Some(i128::from(*val)),
+        });
+    }
+
+    quote! {
+        impl #data_kind_name {
+            // The value as an f64, rounding u64 and i64 values above 2^53, or None for a non numeric or missing value
+            #vis fn to_f64(&self) -> ::std::option::Option<f64> {
+                match self {
+                    #(#to_f64_arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            // The value as an i128, or None for a float, a non numeric or a missing value
+            #vis fn to_i128(&self) -> ::std::option::Option<i128> {
+                match self {
+                    #(#to_i128_arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            // The value or the elements of the array as f64s, with missing values as NaN, or None for a non numeric value
+            #vis fn to_f64_vec(&self) -> ::std::option::Option<::std::vec::Vec<f64>> {
+                match self {
+                    #(#to_f64_vec_arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    }
+}
+
+// The primitive held by the variant, when the value stored in the generated enum is numeric
+fn numeric_primitive(element: &ElementType, options: &CastOptions) -> Option<String> {
+    match element {
+        ElementType::Primitive(primitive) if options.into.is_none() && options.flags.is_none() => {
+            Some(primitive.to_string())
+        }
+        _ => None,
+    }
+}
+
+fn has_sentinel(options: &CastOptions) -> bool {
+    options.missing.is_some() || options.invalid.is_some()
+}
+
+// Converts the value to an f64, exactly except for u64 and i64 which have more than 53 bits
+fn widen_to_f64(primitive: &str, value: TokenStream2) -> TokenStream2 {
+    match primitive {
+        "f64" => value,
+        "u64" | "i64" => quote! { #value as f64 },
+        _ => quote! { f64::from(#value) },
+    }
+}
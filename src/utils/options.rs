@@ -0,0 +1,341 @@
+// options.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{parse::ParseStream, Token};
+
+use super::{element::ElementType, suggest::did_you_mean};
+
+/// The options accepted after the cast type, e.g. `#[cast(from_be_bytes => u8, into = Mode)]`
+const OPTION_NAMES: [&str; 10] = [
+    "into",
+    "on_unknown",
+    "flags",
+    "undefined_bits",
+    "bitmask",
+    "bit_order",
+    "encoding",
+    "expect",
+    "missing",
+    "invalid",
+];
+
+/// How the `flags` option treats bits that are not defined by the flags type
+const UNDEFINED_BITS: [&str; 3] = ["retain", "truncate", "reject"];
+
+/// The order the bits of a `bitmask` are expanded in
+const BIT_ORDERS: [&str; 2] = ["lsb_first", "msb_first"];
+
+/// The signed integer encodings other than two's complement
+const ENCODINGS: [&str; 2] = ["sign_magnitude", "ones_complement"];
+
+/// Represents the `key = value` options following the cast type of a variant.
+#[derive(Clone, Debug, Default)]
+pub struct CastOptions {
+    /// The type the decoded value is converted into with `TryFrom`
+    pub into: Option<syn::Path>,
+    /// The tuple variant of the `into` type that holds codes it does not recognise. Unrecognised
+    /// codes fail the parse when this is not set
+    pub on_unknown: Option<Ident>,
+    /// The `bitflags` type the decoded word is converted into
+    pub flags: Option<syn::Path>,
+    /// How bits not defined by the `flags` type are handled, one of `UNDEFINED_BITS`. Undefined
+    /// bits are retained when this is not set
+    pub undefined_bits: Option<Ident>,
+    /// Whether a `[bool; N]` array is expanded from the bits of the minimal number of bytes
+    pub bitmask: bool,
+    /// The order bitmask bits are expanded in, one of `BIT_ORDERS`. Bits are expanded from the
+    /// least significant bit when this is not set
+    pub bit_order: Option<Ident>,
+    /// The encoding of a signed integer, one of `ENCODINGS`. Integers are two's complement when
+    /// this is not set
+    pub encoding: Option<Ident>,
+    /// The value the decoded integer must equal, e.g. a signature such as `0xCAFEBABE`
+    pub expect: Option<syn::Expr>,
+    /// The sentinel marking a missing value, decoded as `None`
+    pub missing: Option<syn::Expr>,
+    /// The sentinel marking an invalid value, decoded as `None`
+    pub invalid: Option<syn::Expr>,
+}
+
+impl CastOptions {
+    /// Parses the options following the cast type, each preceded by a comma. A trailing comma is
+    /// accepted.
+    pub fn parse_trailing(input: ParseStream) -> syn::Result<Self> {
+        let mut cast_options = CastOptions::default();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse().map_err(|error| {
+                syn::Error::new(error.span(), "Expected a cast option such as into = Mode")
+            })?;
+            if !OPTION_NAMES.contains(&key.to_string().as_str()) {
+                return Err(unknown_option(&key));
+            }
+            if key == "bitmask" {
+                cast_options.bitmask = true;
+                continue;
+            }
+            if !input.peek(Token![=]) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Expected `{} = ...`", key),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "into" => cast_options.into = Some(input.parse()?),
+                "on_unknown" => {
+                    let fallback: Ident = input.parse()?;
+                    if fallback != "fail" {
+                        cast_options.on_unknown = Some(fallback);
+                    }
+                }
+                "flags" => {
+                    if !cfg!(feature = "bitflags") {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "The `flags` option requires the `bitflags` feature of binary_type_cast",
+                        ));
+                    }
+                    cast_options.flags = Some(input.parse()?)
+                }
+                "undefined_bits" => {
+                    cast_options.undefined_bits = Some(one_of(input, &UNDEFINED_BITS)?)
+                }
+                "bit_order" => cast_options.bit_order = Some(one_of(input, &BIT_ORDERS)?),
+                "encoding" => cast_options.encoding = Some(one_of(input, &ENCODINGS)?),
+                "expect" => cast_options.expect = Some(input.parse()?),
+                "missing" => cast_options.missing = Some(input.parse()?),
+                "invalid" => cast_options.invalid = Some(input.parse()?),
+                _ => return Err(unknown_option(&key)),
+            }
+        }
+        Ok(cast_options)
+    }
+
+    /// Checks that the options can be applied to the element
+    pub fn check_element(&self, element: &ElementType) -> Result<(), syn::Error> {
+        if let (None, Some(fallback)) = (&self.into, &self.on_unknown) {
+            return Err(syn::Error::new(
+                fallback.span(),
+                "`on_unknown` requires an `into` type",
+            ));
+        }
+        if let Some(into) = &self.into {
+            if !matches!(element, ElementType::Primitive(_)) {
+                return Err(syn::Error::new_spanned(
+                    into,
+                    "`into` can only be applied to primitive cast types",
+                ));
+            }
+        }
+        if let (None, Some(handling)) = (&self.flags, &self.undefined_bits) {
+            return Err(syn::Error::new(
+                handling.span(),
+                "`undefined_bits` requires a `flags` type",
+            ));
+        }
+        if let (false, Some(bit_order)) = (self.bitmask, &self.bit_order) {
+            return Err(syn::Error::new(
+                bit_order.span(),
+                "`bit_order` requires the `bitmask` option",
+            ));
+        }
+        if let Some(encoding) = &self.encoding {
+            if !element.is_signed_integer() {
+                return Err(syn::Error::new(
+                    encoding.span(),
+                    "`encoding` can only be applied to signed integer cast types",
+                ));
+            }
+        }
+        if let Some(flags) = &self.flags {
+            if self.into.is_some() {
+                return Err(syn::Error::new_spanned(
+                    flags,
+                    "`flags` cannot be combined with `into`",
+                ));
+            }
+            if !element.is_integer() {
+                return Err(syn::Error::new_spanned(
+                    flags,
+                    "`flags` can only be applied to integer cast types",
+                ));
+            }
+        }
+        if let Some(expect) = &self.expect {
+            if !element.is_integer() {
+                return Err(syn::Error::new_spanned(
+                    expect,
+                    "`expect` can only be applied to integer cast types",
+                ));
+            }
+            if self.into.is_some() || self.flags.is_some() {
+                return Err(syn::Error::new_spanned(
+                    expect,
+                    "`expect` cannot be combined with `into` or `flags`",
+                ));
+            }
+        }
+        if let Some(sentinel) = self.missing.as_ref().or(self.invalid.as_ref()) {
+            if !matches!(element, ElementType::Primitive(_)) {
+                return Err(syn::Error::new_spanned(
+                    sentinel,
+                    "`missing` and `invalid` can only be applied to primitive cast types",
+                ));
+            }
+            if self.into.is_some() || self.flags.is_some() || self.expect.is_some() {
+                return Err(syn::Error::new_spanned(
+                    sentinel,
+                    "`missing` and `invalid` cannot be combined with `into`, `flags` or `expect`",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the statements that fail the parse of `variant` with a `mismatch` error when the
+    /// decoded `bytes` differ from the expected value. Empty when no value is expected
+    pub fn expect_check(
+        &self,
+        element: &ElementType,
+        variant: &Ident,
+        mismatch: &Ident,
+    ) -> TokenStream2 {
+        let Some(expected) = &self.expect else {
+            return quote! {};
+        };
+        quote! {
+            let expected: #element = #expected;
+            if bytes != expected {
+                return ::std::result::Result::Err(::nom::Err::Error(E::from_external_error(
+                    input,
+                    ::nom::error::ErrorKind::Verify,
+                    #mismatch {
+                        variant: ::std::stringify!(#variant),
+                        expected: ::std::format!("{:#x}", expected),
+                        found: ::std::format!("{:#x}", bytes),
+                    },
+                )));
+            }
+        }
+    }
+
+    /// Wraps the parser of the element so it produces the value stored in the generated enum
+    pub fn wrap_parser(&self, element: &ElementType, parser: TokenStream2) -> TokenStream2 {
+        // Reinterpret the two's complement value decoded by nom in the declared encoding
+        let parser = match &self.encoding {
+            // The sign bit is followed by the magnitude
+            Some(encoding) if encoding == "sign_magnitude" => quote! {
+                ::nom::combinator::map(#parser, |raw: #element| -> #element {
+                    if raw < 0 { -(raw & #element::MAX) } else { raw }
+                })
+            },
+            // Negative values have every bit of the magnitude inverted, which two's complement
+            // reads as one less than the value
+            Some(_) => quote! {
+                ::nom::combinator::map(#parser, |raw: #element| -> #element {
+                    if raw < 0 { raw + 1 } else { raw }
+                })
+            },
+            None => parser,
+        };
+        match (&self.into, &self.on_unknown) {
+            (Some(into), Some(fallback)) => quote! {
+                ::nom::combinator::map(#parser, |raw: #element| {
+                    <#into as ::std::convert::TryFrom<#element>>::try_from(raw)
+                        .unwrap_or_else(|_| <#into>::#fallback(raw))
+                })
+            },
+            (Some(into), None) => quote! {
+                ::nom::combinator::map_opt(#parser, |raw: #element| {
+                    <#into as ::std::convert::TryFrom<#element>>::try_from(raw).ok()
+                })
+            },
+            (None, _) if self.missing.is_some() || self.invalid.is_some() => {
+                // NaN never compares equal, so a NaN sentinel matches any NaN
+                let matches = |sentinel: &syn::Expr| {
+                    if element.is_float() {
+                        quote! {
+                            { let sentinel: #element = #sentinel; raw == sentinel || (raw.is_nan() && sentinel.is_nan()) }
+                        }
+                    } else {
+                        quote! { raw == #sentinel }
+                    }
+                };
+                let sentinels: Vec<TokenStream2> = self
+                    .missing
+                    .iter()
+                    .chain(&self.invalid)
+                    .map(matches)
+                    .collect();
+                quote! {
+                    ::nom::combinator::map(#parser, |raw: #element| -> ::std::option::Option<#element> {
+                        if #(#sentinels)||* { ::std::option::Option::None } else { ::std::option::Option::Some(raw) }
+                    })
+                }
+            }
+            (None, _) => match (&self.flags, &self.undefined_bits) {
+                (Some(flags), Some(handling)) if handling == "reject" => quote! {
+                    ::nom::combinator::map_opt(#parser, <#flags>::from_bits)
+                },
+                (Some(flags), Some(handling)) if handling == "truncate" => quote! {
+                    ::nom::combinator::map(#parser, <#flags>::from_bits_truncate)
+                },
+                (Some(flags), _) => quote! {
+                    ::nom::combinator::map(#parser, <#flags>::from_bits_retain)
+                },
+                (None, _) => parser,
+            },
+        }
+    }
+
+    /// Writes the type of the value stored in the generated enum
+    pub fn value_type(&self, element: &ElementType, tokens: &mut TokenStream2) {
+        match (&self.into, &self.flags) {
+            (Some(into), _) => into.to_tokens(tokens),
+            (None, Some(flags)) => flags.to_tokens(tokens),
+            (None, None) if self.missing.is_some() || self.invalid.is_some() => {
+                tokens.extend(quote! { ::std::option::Option<#element> })
+            }
+            (None, None) => element.to_tokens(tokens),
+        }
+    }
+}
+
+// Parses the value of an option that must be one of the given names, written either as an
+// identifier or as a string literal
+pub fn one_of(input: ParseStream, accepted: &[&str]) -> syn::Result<Ident> {
+    let ident = if input.peek(syn::LitStr) {
+        let name: syn::LitStr = input.parse()?;
+        Ident::new(&name.value(), name.span())
+    } else {
+        input.parse()?
+    };
+    if !accepted.contains(&ident.to_string().as_str()) {
+        return Err(syn::Error::new(
+            ident.span(),
+            format!(
+                "Expected one of: {}, found {}{}",
+                accepted.join(", "),
+                ident,
+                did_you_mean(&ident.to_string(), accepted)
+            ),
+        ));
+    }
+    Ok(ident)
+}
+
+fn unknown_option(key: &Ident) -> syn::Error {
+    syn::Error::new(
+        key.span(),
+        format!(
+            "Unknown cast option `{}`, expected one of: {}{}",
+            key,
+            OPTION_NAMES.join(", "),
+            did_you_mean(&key.to_string(), &OPTION_NAMES)
+        ),
+    )
+}
@@ -0,0 +1,146 @@
+// padding.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use super::suggest::did_you_mean;
+
+/// The padding kinds accepted as a variant kind, e.g. `#[cast(skip = 3)]`
+pub const PADDING_KINDS: [&str; 2] = ["skip", "align"];
+
+/// The options accepted after the padding kind
+const PADDING_OPTIONS: [&str; 1] = ["zeroed"];
+
+/// Returns true if the identifier names a padding kind
+pub fn is_padding(kind: &str) -> bool {
+    PADDING_KINDS.contains(&kind)
+}
+
+/// Represents reserved bytes that are consumed without producing a value. `skip` consumes a fixed
+/// number of bytes while `align` consumes the bytes up to the next offset that is a multiple of
+/// the alignment.
+#[derive(Clone, Debug)]
+pub struct Padding {
+    /// One of `PADDING_KINDS`
+    pub kind: Ident,
+    /// The number of bytes skipped or the alignment in bytes
+    pub amount: syn::Expr,
+    /// Whether the parse fails unless every consumed byte is zero
+    pub zeroed: bool,
+}
+
+impl Parse for Padding {
+    /// Parses the padding kind and its amount, e.g. `align = 8`, followed by the comma separated
+    /// options.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kind: Ident = input.parse()?;
+        if !input.peek(Token![=]) {
+            return Err(syn::Error::new(
+                kind.span(),
+                format!("Expected `{} = ...` with the number of bytes", kind),
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        let amount: syn::Expr = input.parse()?;
+        check_amount(&kind, &amount)?;
+
+        let mut zeroed = false;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            if key != "zeroed" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "Unknown padding option `{}`, expected one of: {}{}",
+                        key,
+                        PADDING_OPTIONS.join(", "),
+                        did_you_mean(&key.to_string(), &PADDING_OPTIONS)
+                    ),
+                ));
+            }
+            zeroed = true;
+        }
+
+        Ok(Padding {
+            kind,
+            amount,
+            zeroed,
+        })
+    }
+}
+
+impl Padding {
+    /// Whether the padding depends on the offset of the input within the record
+    pub fn is_align(&self) -> bool {
+        self.kind == "align"
+    }
+
+    /// Builds the parser expression that consumes the padding. Alignment is measured from the
+    /// `offset: usize` of the input within the record, and an alignment that evaluates to zero
+    /// fails the parse instead of dividing by zero.
+    pub fn parser(&self) -> TokenStream2 {
+        let amount = &self.amount;
+        let length = if self.is_align() {
+            quote! {
+                let alignment: usize = #amount;
+                let length = match offset.checked_rem(alignment) {
+                    ::std::option::Option::Some(0) => 0,
+                    ::std::option::Option::Some(remainder) => alignment - remainder,
+                    ::std::option::Option::None => {
+                        return ::std::result::Result::Err(::nom::Err::Failure(E::from_error_kind(
+                            input,
+                            ::nom::error::ErrorKind::Fail,
+                        )))
+                    }
+                };
+            }
+        } else {
+            quote! { let length: usize = #amount; }
+        };
+        let take = quote! { ::nom::bytes::complete::take::<usize, &'a [u8], E>(length) };
+        let take = if self.zeroed {
+            quote! {
+                ::nom::combinator::verify(#take, |bytes: &[u8]| bytes.iter().all(|byte| *byte == 0))
+            }
+        } else {
+            take
+        };
+        quote! {
+            |input: &'a [u8]| {
+                #length
+                #take(input)
+            }
+        }
+    }
+}
+
+// Rejects literal amounts that are zero or negative, which would skip nothing or never align
+fn check_amount(kind: &Ident, amount: &syn::Expr) -> syn::Result<()> {
+    let positive = match amount {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(literal),
+            ..
+        }) => literal.base10_parse::<u128>()? > 0,
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            ..
+        }) => false,
+        // Const expressions are checked by the compiler and, for `align`, when parsing
+        _ => true,
+    };
+    if positive {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            amount,
+            format!("`{}` requires a positive number of bytes", kind),
+        ))
+    }
+}
@@ -0,0 +1,48 @@
+// suggest.rs
+
+/// Returns the candidate closest to `found` by edit distance, when it is close enough to be a
+/// likely typo
+pub fn closest<'a>(found: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let found = found.to_lowercase();
+    let threshold = (found.chars().count() / 3).max(1);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(&found, &candidate.to_lowercase()), *candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Formats a help line suggesting the closest candidate, empty when none is close
+pub fn did_you_mean(found: &str, candidates: &[&str]) -> String {
+    match closest(found, candidates) {
+        Some(candidate) => format!("\nhelp: did you mean `{}`?", candidate),
+        None => String::new(),
+    }
+}
+
+// The optimal string alignment distance: the number of single character insertions, deletions,
+// substitutions and transpositions of adjacent characters turning one string into the other
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
@@ -0,0 +1,112 @@
+// try_from.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+
+use quote::{quote, ToTokens};
+use std::collections::HashMap;
+
+use super::{kind::type_name, CastTypeData};
+
+pub fn build_type_variants_map(
+    cast_type_data: &CastTypeData,
+) -> HashMap<String, Vec<proc_macro2::Ident>> {
+    // Initialize a HashMap to store the relationship between cast types and their corresponding Enum variants
+    let mut type_variants_map: HashMap<String, Vec<proc_macro2::Ident>> = HashMap::new();
+
+    // Handle primitive types
+    for (cast_type, variant) in cast_type_data
+        .cast_types
+        .iter()
+        .zip(cast_type_data.variants.iter())
+    {
+        let cast_type_str = cast_type.to_token_stream().to_string();
+        type_variants_map
+            .entry(cast_type_str)
+            .or_default()
+            .push(variant.clone());
+    }
+
+    // Handle string types, held as the standard library String whatever `String` names where the enum is defined
+    for string_variant in cast_type_data.string_variants.iter() {
+        let string_type_str = quote!(::std::string::String).to_string();
+        type_variants_map
+            .entry(string_type_str)
+            .or_default()
+            .push(string_variant.clone());
+    }
+
+    // Handle complex types
+    for (complex_cast_type, complex_variant) in cast_type_data
+        .complex_cast_types_group
+        .iter()
+        .zip(cast_type_data.complex_variants.iter())
+    {
+        let complex_cast_type_str = complex_cast_type.to_string();
+        type_variants_map
+            .entry(complex_cast_type_str)
+            .or_default()
+            .push(complex_variant.clone());
+    }
+
+    // Handle checksums, which store the checksum read from the input
+    for (checksum, checksum_variant) in cast_type_data
+        .checksums
+        .iter()
+        .zip(cast_type_data.checksum_variants.iter())
+    {
+        type_variants_map
+            .entry(checksum.value_type().to_string())
+            .or_default()
+            .push(checksum_variant.clone());
+    }
+    type_variants_map
+}
+
+/// Generates `TryFrom<DataTypesCast>` for every cast type, plus `TryFrom<&DataTypesCast>` for
+/// references to it. `TryInto` is implemented through the blanket implementation, so both
+/// `f32::try_from(value)` and `value.try_into()` work.
+pub fn generate_try_from_impls(
+    type_variants_map: &HashMap<String, Vec<Ident>>,
+    data_kind_name: &Ident,
+) -> TokenStream2 {
+    // Initialize a vector to store the generated TryFrom trait implementations
+    let mut try_from_impls = Vec::new();
+    // Iterate through the type_variants_map to generate trait implementations for each cast type
+    for (cast_type_str, variants) in type_variants_map {
+        let cast_type: TokenStream2 = cast_type_str.parse().unwrap();
+        let cast_type_name = type_name(&cast_type);
+        // Generate the TryFrom trait implementations for the current cast type, taking the value out of the enum or borrowing it
+        let impl_block = quote! {
+            impl ::std::convert::TryFrom<#data_kind_name> for #cast_type {
+                type Error = ::std::string::String;
+
+                fn try_from(value: #data_kind_name) -> ::std::result::Result<Self, Self::Error> {
+                    match value {
+                        #(#data_kind_name::#variants(val) => ::std::result::Result::Ok(val),)*
+                        #[allow(unreachable_patterns)]
+                        _ => ::std::result::Result::Err(::std::format!("Cannot convert non-compatible {} into {}", ::std::stringify!(#data_kind_name), #cast_type_name)),
+                    }
+                }
+            }
+
+            impl<'a> ::std::convert::TryFrom<&'a #data_kind_name> for &'a #cast_type {
+                type Error = ::std::string::String;
+
+                fn try_from(value: &'a #data_kind_name) -> ::std::result::Result<Self, Self::Error> {
+                    match value {
+                        #(#data_kind_name::#variants(val) => ::std::result::Result::Ok(val),)*
+                        #[allow(unreachable_patterns)]
+                        _ => ::std::result::Result::Err(::std::format!("Cannot convert non-compatible {} into {}", ::std::stringify!(#data_kind_name), #cast_type_name)),
+                    }
+                }
+            }
+        };
+        // Add the generated implementation block to the vector
+        try_from_impls.push(impl_block);
+    }
+    // Combine all the generated implementation blocks into a single TokenStream
+    let generated_impls = quote! {
+        #(#try_from_impls)*
+    };
+
+    generated_impls
+}
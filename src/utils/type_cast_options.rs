@@ -0,0 +1,116 @@
+// type_cast_options.rs
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parenthesized, parse::ParseStream, punctuated::Punctuated, Token};
+
+use super::suggest::did_you_mean;
+
+/// The options accepted by the enum level `#[type_cast(...)]` attribute
+const TYPE_CAST_OPTIONS: [&str; 4] = ["derive", "attr", "name", "vis"];
+
+/// Represents the enum level `#[type_cast(...)]` attributes, which configure the generated `*Cast`
+/// enum, e.g. `#[type_cast(name = "DecodedValue", vis = "pub(crate)", derive(Clone, Debug))]`.
+#[derive(Clone, Debug, Default)]
+pub struct TypeCastOptions {
+    /// The traits derived for the generated enum, replacing the default list when set
+    pub derives: Option<Vec<syn::Path>>,
+    /// The attributes forwarded to the generated enum, without the surrounding `#[...]`
+    pub attrs: Vec<TokenStream2>,
+    /// The name of the generated enum, `{Name}Cast` when not set
+    pub name: Option<Ident>,
+    /// The visibility of the generated enum and of the methods returning it, `pub` when not set
+    pub vis: Option<syn::Visibility>,
+}
+
+impl TypeCastOptions {
+    /// Collects the options of every `type_cast` attribute of the enum
+    pub fn from_attributes(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = TypeCastOptions::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("type_cast")) {
+            attr.parse_args_with(|input: ParseStream| options.parse_into(input))?;
+        }
+        Ok(options)
+    }
+
+    // Parses the comma separated options of one attribute into `self`
+    fn parse_into(&mut self, input: ParseStream) -> syn::Result<()> {
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            let content;
+            match key.to_string().as_str() {
+                "derive" => {
+                    if self.derives.is_some() {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "The derives of the generated enum are already set",
+                        ));
+                    }
+                    parenthesized!(content in input);
+                    let derives = Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?;
+                    self.derives = Some(derives.into_iter().collect());
+                }
+                "attr" => {
+                    parenthesized!(content in input);
+                    self.attrs.push(content.parse()?);
+                }
+                "name" => {
+                    input.parse::<Token![=]>()?;
+                    self.name = Some(if input.peek(syn::LitStr) {
+                        input.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        input.parse()?
+                    });
+                }
+                "vis" => {
+                    input.parse::<Token![=]>()?;
+                    self.vis = Some(if input.peek(syn::LitStr) {
+                        input.parse::<syn::LitStr>()?.parse()?
+                    } else {
+                        input.parse()?
+                    });
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "Unknown type_cast option `{}`, expected one of: {}{}",
+                            key,
+                            TYPE_CAST_OPTIONS.join(", "),
+                            did_you_mean(&key.to_string(), &TYPE_CAST_OPTIONS)
+                        ),
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The name of the generated enum
+    pub fn enum_name(&self, name: &Ident) -> Ident {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format_ident!("{}Cast", name))
+    }
+
+    /// The visibility of the generated items
+    pub fn visibility(&self) -> syn::Visibility {
+        self.vis.clone().unwrap_or_else(|| syn::parse_quote!(pub))
+    }
+
+    /// Builds the derive and forwarded attributes placed above the generated enum
+    pub fn enum_attributes(&self) -> TokenStream2 {
+        let derives = match &self.derives {
+            Some(derives) => quote! { #(#derives),* },
+            // Decided per enum: an enum that should not derive serde lists its derives instead
+            None => quote! { Clone, Debug, ::serde::Serialize, ::serde::Deserialize },
+        };
+        let attrs = &self.attrs;
+        quote! {
+            #[derive(#derives)]
+            #(#[#attrs])*
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    IEEE754LSBSingle,
+    #[cast(from_be_bytes => f32)]
+    IEEE754MSBSingle,
+    #[cast(from_le_bytes => [f64; 2])]
+    DoublePair,
+    #[cast(from_le_bytes => [f64; 1])]
+    DoubleSingleton,
+    #[cast(from_le_bytes => u8, missing = 0xFF)]
+    Level,
+    #[cast(String)]
+    Text,
+    #[cast(skip = 1)]
+    Reserved,
+}
+
+#[test]
+fn scalar_accessors_borrow_every_variant_of_the_type() {
+    let (_, lsb) = DataTypes::IEEE754LSBSingle
+        .parse(&1.5f32.to_le_bytes())
+        .unwrap();
+    let (_, msb) = DataTypes::IEEE754MSBSingle
+        .parse(&2.5f32.to_be_bytes())
+        .unwrap();
+    assert_eq!(lsb.as_f32(), Some(&1.5));
+    assert_eq!(msb.as_f32(), Some(&2.5));
+    assert_eq!(lsb.as_string(), None);
+    assert_eq!(lsb.into_f32(), Some(1.5));
+}
+
+#[test]
+fn array_accessors_match_the_requested_length() {
+    let bytes: Vec<u8> = [1.0f64, 2.0].iter().flat_map(|x| x.to_le_bytes()).collect();
+    let (_, pair) = DataTypes::DoublePair.parse(&bytes).unwrap();
+    assert_eq!(pair.as_f64_array::<2>(), Some(&[1.0, 2.0]));
+    assert_eq!(pair.as_f64_array::<1>(), None);
+    assert_eq!(pair.clone().into_f64_array::<2>(), Some([1.0, 2.0]));
+
+    let (_, singleton) = DataTypes::DoubleSingleton.parse(&bytes).unwrap();
+    assert_eq!(singleton.as_f64_array::<1>(), Some(&[1.0]));
+    assert_eq!(singleton.as_f64_array::<2>(), None);
+}
+
+#[test]
+fn generic_types_are_named_after_their_arguments() {
+    let (_, missing) = DataTypes::Level.parse(&[0xFF]).unwrap();
+    let (_, level) = DataTypes::Level.parse(&[3]).unwrap();
+    assert_eq!(missing.as_option_u8(), Some(&None));
+    assert_eq!(level.as_option_u8(), Some(&Some(3)));
+}
+
+#[test]
+fn into_string_takes_the_string() {
+    let (_, text) = DataTypes::Text.parse(b"abc").unwrap();
+    assert_eq!(text.as_string().map(String::as_str), Some("abc"));
+    assert_eq!(text.into_string(), Some("abc".to_string()));
+}
+
+#[test]
+fn predicates_are_named_after_the_variants() {
+    let (_, lsb) = DataTypes::IEEE754LSBSingle.parse(&[0; 4]).unwrap();
+    let (_, reserved) = DataTypes::Reserved.parse(&[0]).unwrap();
+    assert!(lsb.is_ieee754_lsb_single());
+    assert!(!lsb.is_ieee754_msb_single());
+    assert!(reserved.is_reserved());
+    assert!(!reserved.is_double_pair());
+}
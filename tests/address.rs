@@ -0,0 +1,41 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(ipv4)]
+    SourceAddress,
+    #[cast(ipv6)]
+    SourceAddress6,
+}
+
+#[test]
+fn ipv4_reads_network_byte_order() {
+    let (tail, address) = DataTypes::SourceAddress
+        .parse(&[192, 168, 1, 2, 0xFF])
+        .unwrap();
+    assert_eq!(tail, &[0xFF]);
+    let address: Ipv4Addr = address.try_into().unwrap();
+    assert_eq!(address, Ipv4Addr::new(192, 168, 1, 2));
+    assert_eq!(address.to_string(), "192.168.1.2");
+}
+
+#[test]
+fn ipv6_reads_network_byte_order() {
+    let bytes = [
+        0x20, 0x01, 0x0D, 0xB8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x01,
+    ];
+    let address: Ipv6Addr = decode(DataTypes::SourceAddress6.parse(&bytes));
+    assert_eq!(address, Ipv6Addr::new(0x2001, 0x0DB8, 0, 0, 0, 0, 0, 1));
+    assert_eq!(address.to_string(), "2001:db8::1");
+}
+
+#[test]
+fn addresses_need_every_byte() {
+    assert!(DataTypes::SourceAddress.parse(&[192, 168, 1]).is_err());
+    assert!(DataTypes::SourceAddress6.parse(&[0; 15]).is_err());
+}
@@ -0,0 +1,40 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+const N_CHANNELS: usize = 3;
+const LANES: usize = 2;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => [u8; N_CHANNELS])]
+    Channels,
+    #[cast(from_be_bytes => [u16; 2 * LANES])]
+    Lanes,
+    #[cast(from_le_bytes => [bool; LANES + 8], bitmask)]
+    Flags,
+}
+
+#[test]
+fn const_path_length() {
+    let (tail, channels) = DataTypes::Channels.parse(&[1, 2, 3, 4]).unwrap();
+    assert_eq!(tail, &[4]);
+    let channels: [u8; N_CHANNELS] = channels.try_into().unwrap();
+    assert_eq!(channels, [1, 2, 3]);
+}
+
+#[test]
+fn const_expression_length() {
+    let (tail, lanes) = DataTypes::Lanes.parse(&[0, 1, 0, 2, 0, 3, 0, 4]).unwrap();
+    assert!(tail.is_empty());
+    let lanes: [u16; 4] = lanes.try_into().unwrap();
+    assert_eq!(lanes, [1, 2, 3, 4]);
+}
+
+#[test]
+fn const_expression_bitmask_length() {
+    let (tail, flags) = DataTypes::Flags.parse(&[0b0000_0001, 0b0000_0010]).unwrap();
+    assert!(tail.is_empty());
+    let flags: [bool; 10] = flags.try_into().unwrap();
+    assert!(flags[0] && flags[9]);
+    assert_eq!(flags.iter().filter(|flag| **flag).count(), 2);
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => [bool; 8], bitmask)]
+    Lsb,
+    #[cast(from_le_bytes => [bool; 8], bitmask, bit_order = msb_first)]
+    Msb,
+    #[cast(from_le_bytes => [bool; 16], bitmask, bit_order = lsb_first)]
+    LeWord,
+    #[cast(from_be_bytes => [bool; 16], bitmask)]
+    BeWord,
+    #[cast(from_be_bytes => [bool; 16], bitmask, bit_order = msb_first)]
+    BeWordMsb,
+    #[cast(from_le_bytes => [bool; 12], bitmask)]
+    Lanes,
+    #[cast(from_le_bytes => [bool; 12], bitmask, bit_order = msb_first)]
+    LanesMsb,
+}
+
+// The indices of the set elements
+fn set<const N: usize>(bits: [bool; N]) -> Vec<usize> {
+    (0..N).filter(|&i| bits[i]).collect()
+}
+
+#[test]
+fn lsb_first_starts_from_the_least_significant_bit() {
+    let (tail, bits) = DataTypes::Lsb.parse(&[0b0000_0101, 9]).unwrap();
+    assert_eq!(tail, &[9]);
+    let bits: [bool; 8] = bits.try_into().unwrap();
+    assert_eq!(set(bits), vec![0, 2]);
+}
+
+#[test]
+fn msb_first_starts_from_the_most_significant_bit() {
+    let bits: [bool; 8] = decode(DataTypes::Msb.parse(&[0b0000_0101]));
+    assert_eq!(set(bits), vec![5, 7]);
+}
+
+#[test]
+fn words_are_read_in_the_declared_byte_order() {
+    // 0x0180 little endian, 0x8001 big endian
+    let bytes = [0x80, 0x01];
+    let le: [bool; 16] = decode(DataTypes::LeWord.parse(&bytes));
+    assert_eq!(set(le), vec![7, 8]);
+    let be: [bool; 16] = decode(DataTypes::BeWord.parse(&bytes));
+    assert_eq!(set(be), vec![0, 15]);
+    let be_msb: [bool; 16] = decode(DataTypes::BeWordMsb.parse(&[0x40, 0x02]));
+    assert_eq!(set(be_msb), vec![1, 14]);
+}
+
+#[test]
+fn partial_words_ignore_the_leftover_bits() {
+    // 0x800F: the low nibble and the top bit are set
+    let bytes = [0x0F, 0x80];
+    // Bits 0 to 11, so the top bit is ignored
+    let lanes: [bool; 12] = decode(DataTypes::Lanes.parse(&bytes));
+    assert_eq!(set(lanes), vec![0, 1, 2, 3]);
+    // Bits 15 down to 4, so the low nibble is ignored
+    let lanes: [bool; 12] = decode(DataTypes::LanesMsb.parse(&bytes));
+    assert_eq!(set(lanes), vec![0]);
+}
+
+#[test]
+fn bitmasks_need_every_byte() {
+    assert!(DataTypes::Lanes.parse(&[0xFF]).is_err());
+}
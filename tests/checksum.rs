@@ -0,0 +1,135 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use nom::error::{ErrorKind, FromExternalError, ParseError};
+use serde::{Deserialize, Serialize};
+
+// Every checksum covers the standard check input "123456789", apart from Fletcher-16 which covers "abcde"
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(crc32 = "ieee", over = 0..9)]
+    Ieee,
+    #[cast(crc32 = "castagnoli", over = 0..9)]
+    Castagnoli,
+    #[cast(crc16 = "ccitt", over = 0..9)]
+    Ccitt,
+    #[cast(crc16 = "xmodem", over = 0..9)]
+    Xmodem,
+    #[cast(crc16 = "kermit", over = 0..9)]
+    Kermit,
+    #[cast(crc16 = "kermit", over = 0..9, byte_order = le)]
+    KermitLe,
+    #[cast(fletcher16, over = 0..5)]
+    Fletcher,
+    // A frame whose first two bytes are a header outside the checksum
+    #[cast(crc16 = "xmodem", over = 2..11)]
+    Framed,
+}
+
+// The error of a failed parse, keeping the checksum mismatch when there is one
+#[derive(Debug, PartialEq)]
+enum FrameError {
+    Nom(ErrorKind),
+    Mismatch(DataTypesCastChecksumMismatch),
+}
+
+impl ParseError<&[u8]> for FrameError {
+    fn from_error_kind(_: &[u8], kind: ErrorKind) -> Self {
+        FrameError::Nom(kind)
+    }
+
+    fn append(_: &[u8], _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl FromExternalError<&[u8], DataTypesCastChecksumMismatch> for FrameError {
+    fn from_external_error(
+        _: &[u8],
+        _: ErrorKind,
+        mismatch: DataTypesCastChecksumMismatch,
+    ) -> Self {
+        FrameError::Mismatch(mismatch)
+    }
+}
+
+// The covered bytes followed by the stored checksum
+fn frame(covered: &[u8], stored: &[u8]) -> Vec<u8> {
+    [covered, stored].concat()
+}
+
+#[test]
+fn crc32() {
+    let ieee = frame(b"123456789", &0xCBF43926u32.to_be_bytes());
+    assert_eq!(decode::<u32, _>(DataTypes::Ieee.parse(&ieee)), 0xCBF43926);
+    let castagnoli = frame(b"123456789", &0xE3069283u32.to_be_bytes());
+    assert_eq!(
+        decode::<u32, _>(DataTypes::Castagnoli.parse(&castagnoli)),
+        0xE3069283
+    );
+}
+
+#[test]
+fn crc16() {
+    let ccitt = frame(b"123456789", &0x29B1u16.to_be_bytes());
+    assert_eq!(decode::<u16, _>(DataTypes::Ccitt.parse(&ccitt)), 0x29B1);
+    let xmodem = frame(b"123456789", &0x31C3u16.to_be_bytes());
+    assert_eq!(decode::<u16, _>(DataTypes::Xmodem.parse(&xmodem)), 0x31C3);
+    let kermit = frame(b"123456789", &0x2189u16.to_be_bytes());
+    assert_eq!(decode::<u16, _>(DataTypes::Kermit.parse(&kermit)), 0x2189);
+}
+
+#[test]
+fn fletcher16() {
+    let fletcher = frame(b"abcde", &0xC8F0u16.to_be_bytes());
+    assert_eq!(
+        decode::<u16, _>(DataTypes::Fletcher.parse(&fletcher)),
+        0xC8F0
+    );
+}
+
+#[test]
+fn little_endian_stored_checksum() {
+    let kermit = frame(b"123456789", &0x2189u16.to_le_bytes());
+    assert_eq!(decode::<u16, _>(DataTypes::KermitLe.parse(&kermit)), 0x2189);
+    // The same bytes do not match when the checksum is read big endian
+    assert!(DataTypes::Kermit.parse(&kermit).is_err());
+}
+
+#[test]
+fn range_is_measured_from_the_start_of_the_input() {
+    let framed = frame(b"\x01\x02123456789", &0x31C3u16.to_be_bytes());
+    assert_eq!(decode::<u16, _>(DataTypes::Framed.parse(&framed)), 0x31C3);
+    // Without its header, the frame is two bytes short of the range
+    assert!(DataTypes::Framed.parse(&framed[2..]).is_err());
+}
+
+#[test]
+fn mismatch_reports_expected_and_actual() {
+    let corrupted = frame(b"123456780", &0xCBF43926u32.to_be_bytes());
+    let result = DataTypes::Ieee.parse_with::<FrameError>(&corrupted);
+    let Err(nom::Err::Error(FrameError::Mismatch(mismatch))) = result else {
+        panic!("expected a recoverable checksum mismatch, got {:?}", result);
+    };
+    assert_eq!(mismatch.variant, "Ieee");
+    assert_eq!(mismatch.expected, 0xCBF43926);
+    assert_ne!(mismatch.actual, 0xCBF43926);
+    assert!(mismatch.to_string().starts_with("Ieee checksum mismatch"));
+}
+
+#[test]
+fn short_input_is_eof() {
+    // The covered bytes are missing
+    assert_eq!(
+        DataTypes::Ieee.parse_with::<FrameError>(b"1234").err(),
+        Some(nom::Err::Error(FrameError::Nom(ErrorKind::Eof)))
+    );
+    // The stored checksum is cut short
+    assert_eq!(
+        DataTypes::Ieee
+            .parse_with::<FrameError>(b"123456789\xCB\xF4")
+            .err(),
+        Some(nom::Err::Error(FrameError::Nom(ErrorKind::Eof)))
+    );
+}
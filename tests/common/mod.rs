@@ -0,0 +1,12 @@
+// Helpers shared by the integration tests
+
+/// Unwraps a parse that must consume the whole input and converts the decoded value into `T`
+pub fn decode<T, V>(result: nom::IResult<&[u8], V>) -> T
+where
+    V: TryInto<T>,
+    V::Error: std::fmt::Debug,
+{
+    let (tail, value) = result.unwrap();
+    assert!(tail.is_empty(), "{} bytes left after the parse", tail.len());
+    value.try_into().unwrap()
+}
@@ -0,0 +1,9 @@
+// Malformed `cast` attributes must fail to compile with an error pointing at the offending tokens.
+// The expected messages list the cast types and variant kinds of the default features, and the
+// optional ones are only suggested when their feature is enabled
+#[cfg(not(any(feature = "num-complex", feature = "uuid", feature = "macaddr")))]
+#[test]
+fn malformed_attributes() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(from_le_bytes => [bool; 8])]
+    Value,
+}
+
+fn main() {}
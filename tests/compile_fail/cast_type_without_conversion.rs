@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(u16)]
+    Value,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(from_le_bytes => Complx<f32>)]
+    Impedance,
+}
+
+fn main() {}
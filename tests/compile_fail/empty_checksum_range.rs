@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(crc32 = "ieee", over = 4..4)]
+    Crc,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    Value,
+    Undecorated,
+}
+
+fn main() {}
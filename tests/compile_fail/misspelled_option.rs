@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(from_be_bytes => u8, ito = Mode)]
+    Value,
+}
+
+fn main() {}
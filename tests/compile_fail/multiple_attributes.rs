@@ -0,0 +1,10 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    #[cast(from_be_bytes => f32)]
+    Value,
+}
+
+fn main() {}
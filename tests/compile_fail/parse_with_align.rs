@@ -0,0 +1,13 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+// `align` is measured from the offset of the input, so only `parse_at` is generated
+#[derive(Clone, Copy, Serialize, Deserialize, TypeCast)]
+enum DataTypes {
+    #[cast(align = 8)]
+    Padding,
+}
+
+fn main() {
+    let _ = DataTypes::Padding.parse(&[0; 8]);
+}
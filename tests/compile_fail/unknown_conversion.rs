@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(from_xx_bytes => f32)]
+    Value,
+}
+
+fn main() {}
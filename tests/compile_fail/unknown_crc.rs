@@ -0,0 +1,9 @@
+use binary_type_cast::TypeCast;
+
+#[derive(TypeCast)]
+enum DataTypes {
+    #[cast(crc16 = "modbus", over = 0..8)]
+    Value,
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+#![cfg(feature = "num-complex")]
+
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use num_complex::Complex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => Complex<f32>)]
+    ComplexLSB8,
+    #[cast(from_be_bytes => Complex<f64>)]
+    ComplexMSB16,
+    #[cast(from_le_bytes => [Complex<f64>; 2])]
+    ComplexLSB16Arr2,
+}
+
+fn le_bytes(values: &[f64]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+#[test]
+fn complex_reads_the_real_part_first() {
+    let bytes: Vec<u8> = [1.5f32, -2.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    let single: Complex<f32> = decode(DataTypes::ComplexLSB8.parse(&bytes));
+    assert_eq!(single, Complex::new(1.5, -2.0));
+
+    let bytes: Vec<u8> = [0.25f64, 4.0]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let double: Complex<f64> = decode(DataTypes::ComplexMSB16.parse(&bytes));
+    assert_eq!(double, Complex::new(0.25, 4.0));
+}
+
+#[test]
+fn complex_arrays_read_each_element_in_turn() {
+    let bytes = le_bytes(&[1.0, 2.0, 3.0, 4.0]);
+    let pair: [Complex<f64>; 2] = decode(DataTypes::ComplexLSB16Arr2.parse(&bytes));
+    assert_eq!(pair, [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    assert!(DataTypes::ComplexLSB16Arr2
+        .parse(&le_bytes(&[1.0, 2.0, 3.0]))
+        .is_err());
+}
+
+#[test]
+fn complex_type_names_omit_the_crate_path() {
+    let (_, single) = DataTypes::ComplexLSB8.parse(&[0; 8]).unwrap();
+    assert_eq!(single.type_name(), "Complex<f32>");
+    let (_, pair) = DataTypes::ComplexLSB16Arr2.parse(&[0; 32]).unwrap();
+    assert_eq!(pair.type_name(), "[Complex<f64>; 2]");
+}
@@ -0,0 +1,61 @@
+use binary_type_cast::TypeCast;
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Mode {
+    Idle,
+    Unknown(u8),
+}
+
+impl std::convert::TryFrom<u8> for Mode {
+    type Error = ();
+
+    fn try_from(raw: u8) -> Result<Self, ()> {
+        match raw {
+            0 => Ok(Mode::Idle),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    Single,
+    #[cast(from_be_bytes => [u16; 2])]
+    Pair,
+    #[cast(from_le_bytes => [f32; 2], missing = f32::NAN)]
+    Readings,
+    #[cast(from_le_bytes => u8, into = Mode, on_unknown = Unknown)]
+    State,
+    #[cast(from_be_bytes => [i16; 2], encoding = "ones_complement")]
+    Vector,
+    #[cast(from_le_bytes => [bool; 12], bitmask)]
+    Lanes,
+    #[cast(from_me_bytes => u32)]
+    PdpLong,
+    #[cast(ibm_float => f64)]
+    Legacy,
+    #[cast(from_be_bytes => u16, expect = 0xCAFE)]
+    Signature,
+    #[cast(crc16 = "ccitt", over = 0..2)]
+    Crc,
+    #[cast(magic = b"PK")]
+    Magic,
+    #[cast(skip = 2, zeroed)]
+    Reserved,
+    #[cast(ipv4)]
+    Address,
+    #[cast(String)]
+    Text,
+    #[cast(ignore)]
+    Unparsed,
+}
+
+#[derive(Clone, Copy, Debug, TypeCast)]
+#[type_cast(derive(Clone, Debug, PartialEq))]
+pub enum AlignedTypes {
+    #[cast(align = 4)]
+    Padding,
+    #[cast(from_le_bytes => u16)]
+    Count,
+}
@@ -0,0 +1,83 @@
+use edition_2018::{AlignedTypes, DataTypes, Mode};
+use std::convert::TryInto;
+use std::str::FromStr;
+
+#[test]
+fn arrays_and_options() {
+    let (_, pair) = DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(pair.try_into(), Ok([1u16, 2]));
+    let input: Vec<u8> = [1.5f32, f32::NAN]
+        .iter()
+        .flat_map(|value| value.to_le_bytes().to_vec())
+        .collect();
+    let (_, readings) = DataTypes::Readings.parse(&input).unwrap();
+    assert_eq!(readings.try_into(), Ok([Some(1.5f32), None]));
+    let (_, lanes) = DataTypes::Lanes.parse(&[0b0000_0101, 0]).unwrap();
+    let lanes: [bool; 12] = lanes.try_into().unwrap();
+    assert!(lanes[0] && !lanes[1] && lanes[2]);
+}
+
+#[test]
+fn conversions() {
+    let (_, state) = DataTypes::State.parse(&[7]).unwrap();
+    assert_eq!(state.try_into(), Ok(Mode::Unknown(7)));
+    let (_, vector) = DataTypes::Vector.parse(&[0xFF, 0xFE, 0, 1]).unwrap();
+    assert_eq!(vector.try_into(), Ok([-1i16, 1]));
+    let (_, long) = DataTypes::PdpLong.parse(&[0x02, 0x01, 0x04, 0x03]).unwrap();
+    assert_eq!(long.try_into(), Ok(0x0102_0304u32));
+}
+
+#[test]
+fn checks_and_kinds() {
+    assert!(DataTypes::Signature.parse(&[0xCA, 0xFE]).is_ok());
+    assert!(DataTypes::Signature.parse(&[0xBE, 0xEF]).is_err());
+    assert!(DataTypes::Magic.parse(b"PK\x03\x04").is_ok());
+    assert!(DataTypes::Reserved.parse(&[0, 0]).is_ok());
+    assert!(DataTypes::Unparsed.parse(&[0]).is_err());
+    let (_, text) = DataTypes::Text.parse(b"abc").unwrap();
+    assert_eq!(text.try_into(), Ok("abc".to_string()));
+    assert!(DataTypes::from_str("Address").is_ok());
+    let (tail, _) = AlignedTypes::Padding.parse_at(&[0, 0, 1], 2).unwrap();
+    assert_eq!(tail, &[1]);
+}
+
+#[test]
+fn array_accessors() {
+    let (_, pair) = DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(pair.as_u16_array::<2>(), Some(&[1, 2]));
+    assert_eq!(pair.as_u16_array::<3>(), None);
+    assert_eq!(pair.into_u16_array::<2>(), Some([1, 2]));
+    let (_, lanes) = DataTypes::Lanes.parse(&[0b0000_0001, 0]).unwrap();
+    let expected = [
+        true, false, false, false, false, false, false, false, false, false, false, false,
+    ];
+    assert_eq!(lanes.as_bool_array::<12>(), Some(&expected));
+    assert_eq!(lanes.into_bool_array::<12>(), Some(expected));
+}
+
+#[test]
+fn get_by_type() {
+    use edition_2018::DataTypesCastGet;
+
+    let (_, pair) = DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(DataTypesCastGet::<[u16; 2]>::get(&pair), Some(&[1, 2]));
+    assert_eq!(DataTypesCastGet::<f32>::get(&pair), None);
+}
+
+#[test]
+fn try_from_by_value_and_by_reference() {
+    use std::convert::TryFrom;
+
+    let (_, pair) = DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(<&[u16; 2]>::try_from(&pair), Ok(&[1, 2]));
+    assert!(<&f32>::try_from(&pair).is_err());
+    assert_eq!(<[u16; 2]>::try_from(pair), Ok([1, 2]));
+}
+
+#[test]
+fn numeric_widening() {
+    let (_, pair) = DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(pair.to_f64_vec(), Some(vec![1.0, 2.0]));
+    let (_, long) = DataTypes::PdpLong.parse(&[0x02, 0x01, 0x04, 0x03]).unwrap();
+    assert_eq!(long.to_i128(), Some(0x0102_0304));
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_be_bytes => i16, encoding = "sign_magnitude")]
+    SignMagnitude,
+    #[cast(from_be_bytes => i16, encoding = "ones_complement")]
+    OnesComplement,
+    #[cast(from_le_bytes => i8, encoding = "sign_magnitude")]
+    SignMagnitudeByte,
+    #[cast(from_be_bytes => [i16; 3], encoding = "sign_magnitude")]
+    SignMagnitudeVector,
+    #[cast(from_be_bytes => [i16; 3], encoding = "ones_complement")]
+    OnesComplementVector,
+}
+
+#[test]
+fn sign_magnitude() {
+    let decode_i16 = |input: &[u8]| decode::<i16, _>(DataTypes::SignMagnitude.parse(input));
+    assert_eq!(decode_i16(&[0x00, 0x05]), 5);
+    assert_eq!(decode_i16(&[0x80, 0x05]), -5);
+    assert_eq!(decode_i16(&[0x7F, 0xFF]), i16::MAX);
+    assert_eq!(decode_i16(&[0xFF, 0xFF]), -i16::MAX);
+}
+
+#[test]
+fn ones_complement() {
+    let decode_i16 = |input: &[u8]| decode::<i16, _>(DataTypes::OnesComplement.parse(input));
+    assert_eq!(decode_i16(&[0x00, 0x05]), 5);
+    assert_eq!(decode_i16(&[0xFF, 0xFA]), -5);
+    assert_eq!(decode_i16(&[0x7F, 0xFF]), i16::MAX);
+    assert_eq!(decode_i16(&[0x80, 0x00]), -i16::MAX);
+}
+
+#[test]
+fn negative_zero_decodes_to_zero() {
+    assert_eq!(
+        decode::<i16, _>(DataTypes::SignMagnitude.parse(&[0x80, 0x00])),
+        0
+    );
+    assert_eq!(
+        decode::<i16, _>(DataTypes::OnesComplement.parse(&[0xFF, 0xFF])),
+        0
+    );
+    assert_eq!(
+        decode::<i8, _>(DataTypes::SignMagnitudeByte.parse(&[0x80])),
+        0
+    );
+}
+
+#[test]
+fn arrays_decode_every_element() {
+    let input = [0x80, 0x01, 0x00, 0x02, 0x80, 0x00];
+    let vector: [i16; 3] = decode(DataTypes::SignMagnitudeVector.parse(&input));
+    assert_eq!(vector, [-1, 2, 0]);
+    let input = [0xFF, 0xFE, 0x00, 0x02, 0xFF, 0xFF];
+    let vector: [i16; 3] = decode(DataTypes::OnesComplementVector.parse(&input));
+    assert_eq!(vector, [-1, 2, 0]);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "bitflags")]
+
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct Status: u16 {
+        const READY = 0b0001;
+        const ERROR = 0b0010;
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => u16, flags = Status)]
+    Default,
+    #[cast(from_le_bytes => u16, flags = Status, undefined_bits = retain)]
+    Retain,
+    #[cast(from_be_bytes => u16, flags = Status, undefined_bits = truncate)]
+    Truncate,
+    #[cast(from_le_bytes => u16, flags = Status, undefined_bits = reject)]
+    Reject,
+}
+
+// READY with the undefined bit 0b0100 set
+const UNDEFINED_LE: [u8; 2] = [0b0101, 0];
+
+#[test]
+fn defined_bits_convert_into_the_flags() {
+    for data_type in [DataTypes::Default, DataTypes::Retain, DataTypes::Reject] {
+        let status: Status = decode(data_type.parse(&[0b0011, 0]));
+        assert_eq!(status, Status::READY | Status::ERROR);
+    }
+}
+
+#[test]
+fn retain_keeps_undefined_bits() {
+    for data_type in [DataTypes::Default, DataTypes::Retain] {
+        let status: Status = decode(data_type.parse(&UNDEFINED_LE));
+        assert_eq!(status.bits(), 0b0101);
+        assert!(status.contains(Status::READY));
+    }
+}
+
+#[test]
+fn truncate_drops_undefined_bits() {
+    let status: Status = decode(DataTypes::Truncate.parse(&[0xFF, 0b0101]));
+    assert_eq!(status, Status::READY);
+}
+
+#[test]
+fn reject_fails_on_undefined_bits() {
+    assert!(matches!(
+        DataTypes::Reject.parse(&UNDEFINED_LE),
+        Err(nom::Err::Error(nom::error::Error {
+            code: nom::error::ErrorKind::MapOpt,
+            ..
+        }))
+    ));
+}
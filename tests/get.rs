@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    Single,
+    #[cast(from_be_bytes => [f32; 2])]
+    SingleArr,
+    #[cast(String)]
+    Text,
+}
+
+fn field<'a, T>(fields: &'a BTreeMap<&str, DataTypesCast>, name: &str) -> Option<&'a T>
+where
+    DataTypesCast: DataTypesCastGet<T>,
+{
+    fields.get(name).and_then(DataTypesCastGet::get)
+}
+
+fn fields() -> BTreeMap<&'static str, DataTypesCast> {
+    let pair: Vec<u8> = [1.0f32, 2.0].iter().flat_map(|x| x.to_be_bytes()).collect();
+    BTreeMap::from([
+        (
+            "Single",
+            DataTypes::Single.parse(&0.5f32.to_le_bytes()).unwrap().1,
+        ),
+        ("SingleArr", DataTypes::SingleArr.parse(&pair).unwrap().1),
+        ("Text", DataTypes::Text.parse(b"abc").unwrap().1),
+    ])
+}
+
+#[test]
+fn get_borrows_by_the_requested_type() {
+    let fields = fields();
+    assert_eq!(field::<f32>(&fields, "Single"), Some(&0.5));
+    assert_eq!(field::<[f32; 2]>(&fields, "SingleArr"), Some(&[1.0, 2.0]));
+    assert_eq!(
+        field::<String>(&fields, "Text").map(String::as_str),
+        Some("abc")
+    );
+    assert_eq!(field::<f32>(&fields, "Text"), None);
+    assert_eq!(field::<f32>(&fields, "Missing"), None);
+}
+
+#[test]
+fn take_returns_the_value_or_the_enum() {
+    let mut fields = fields();
+    let single = fields.remove("Single").unwrap();
+    assert_eq!(DataTypesCastGet::<f32>::take(single).ok(), Some(0.5));
+
+    let text = fields.remove("Text").unwrap();
+    let text = DataTypesCastGet::<f32>::take(text).unwrap_err();
+    assert_eq!(
+        DataTypesCastGet::<String>::take(text).ok(),
+        Some("abc".to_string())
+    );
+}
@@ -0,0 +1,177 @@
+// The generated code only refers to fully qualified paths, so the derive compiles in a module
+// without any imports. The tests/edition-2018 crate checks the same under the 2018 prelude
+mod no_imports {
+    #[derive(Clone, Copy, Debug, binary_type_cast::TypeCast)]
+    pub enum DataTypes {
+        #[cast(from_le_bytes => f32)]
+        Single,
+        #[cast(from_be_bytes => [u16; 2])]
+        Pair,
+        #[cast(from_be_bytes => u8, missing = 0xFF)]
+        Level,
+        #[cast(ipv4)]
+        Address,
+        #[cast(crc16 = "ccitt", over = 0..2)]
+        Crc,
+        #[cast(magic = b"PK")]
+        Signature,
+        #[cast(skip = 2, zeroed)]
+        Reserved,
+        #[cast(String)]
+        Text,
+    }
+}
+
+mod no_serde {
+    #[derive(Clone, Copy, Debug, binary_type_cast::TypeCast)]
+    #[type_cast(derive(Clone, Debug, PartialEq))]
+    pub enum DataTypes {
+        #[cast(from_le_bytes => u16)]
+        Count,
+    }
+}
+
+// The generated code does not pick up local types, aliases and macros named like those of the prelude
+#[allow(dead_code, unused_macros)]
+mod shadowed {
+    type Result<T> = std::result::Result<T, ()>;
+    struct String;
+    struct Option;
+    struct Vec;
+    struct Box;
+    struct Ok<T>(T);
+    struct Err<T>(T);
+    struct Some<T>(T);
+    struct None;
+
+    macro_rules! format {
+        ($($tokens:tt)*) => {
+            compile_error!("the generated code called a local format!")
+        };
+    }
+    macro_rules! stringify {
+        ($($tokens:tt)*) => {
+            compile_error!("the generated code called a local stringify!")
+        };
+    }
+    macro_rules! write {
+        ($($tokens:tt)*) => {
+            compile_error!("the generated code called a local write!")
+        };
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Mode {
+        Idle,
+        Unknown(u8),
+    }
+
+    impl std::convert::TryFrom<u8> for Mode {
+        type Error = ();
+
+        fn try_from(raw: u8) -> std::result::Result<Self, ()> {
+            match raw {
+                0 => std::result::Result::Ok(Mode::Idle),
+                _ => std::result::Result::Err(()),
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, binary_type_cast::TypeCast)]
+    #[type_cast(derive(Clone, Debug, PartialEq))]
+    pub enum DataTypes {
+        #[cast(from_le_bytes => u16, expect = 0xCAFE)]
+        Signature,
+        #[cast(from_be_bytes => [u16; 2])]
+        Pair,
+        #[cast(from_be_bytes => u8, missing = 0xFF)]
+        Level,
+        #[cast(crc16 = "ccitt", over = 0..2)]
+        Crc,
+        #[cast(magic = b"PK")]
+        Magic,
+        #[cast(skip = 2)]
+        Reserved,
+        #[cast(String)]
+        Text,
+        #[cast(ignore)]
+        Unparsed,
+        #[cast(from_le_bytes => [f32; 2], invalid = f32::NAN)]
+        Readings,
+        #[cast(from_le_bytes => u8, into = Mode, on_unknown = Unknown)]
+        State,
+        #[cast(from_be_bytes => i16, encoding = "sign_magnitude")]
+        Temperature,
+        #[cast(from_le_bytes => [bool; 12], bitmask, bit_order = msb_first)]
+        Lanes,
+        #[cast(from_me_bytes => u32)]
+        PdpLong,
+        #[cast(ipv6)]
+        Address,
+        #[cast(vax_f => f32)]
+        Legacy,
+    }
+
+    // `align` variants are parsed through `parse_at`, which the other enum does not generate
+    #[derive(Clone, Copy, Debug, binary_type_cast::TypeCast)]
+    #[type_cast(derive(Clone, Debug, PartialEq))]
+    pub enum AlignedTypes {
+        #[cast(align = 4, zeroed)]
+        Padding,
+        #[cast(from_le_bytes => u16)]
+        Count,
+    }
+
+    #[cfg(all(feature = "num-complex", feature = "uuid", feature = "macaddr"))]
+    #[derive(Clone, Copy, Debug, binary_type_cast::TypeCast)]
+    #[type_cast(derive(Clone, Debug, PartialEq))]
+    pub enum FeatureTypes {
+        #[cast(from_le_bytes => [Complex<f32>; 2])]
+        Impedance,
+        #[cast(guid_le)]
+        Guid,
+        #[cast(mac)]
+        Hardware,
+    }
+}
+
+#[test]
+fn parses_with_shadowed_prelude_names() {
+    use std::str::FromStr;
+
+    let (_, pair) = shadowed::DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(<&[u16; 2]>::try_from(&pair), Ok(&[1, 2]));
+    let (_, level) = shadowed::DataTypes::Level.parse(&[0xFF]).unwrap();
+    assert_eq!(level, shadowed::DataTypesCast::Level(None));
+    let (_, text) = shadowed::DataTypes::Text.parse(b"abc").unwrap();
+    assert_eq!(String::try_from(text), Ok("abc".to_string()));
+    assert!(shadowed::DataTypes::Signature.parse(&[0xFE, 0xCA]).is_ok());
+    assert!(shadowed::DataTypes::Unparsed.parse(&[0]).is_err());
+    assert!(shadowed::DataTypes::from_str("Pair").is_ok());
+    let (tail, _) = shadowed::AlignedTypes::Padding
+        .parse_at(&[0, 0, 1], 2)
+        .unwrap();
+    assert_eq!(tail, &[1]);
+}
+
+#[test]
+fn parses_without_imports() {
+    let (tail, pair) = no_imports::DataTypes::Pair.parse(&[0, 1, 0, 2, 3]).unwrap();
+    assert_eq!(tail, &[3]);
+    let pair: [u16; 2] = pair.try_into().unwrap();
+    assert_eq!(pair, [1, 2]);
+    let (_, address) = no_imports::DataTypes::Address
+        .parse(&[10, 0, 0, 1])
+        .unwrap();
+    let address: std::net::Ipv4Addr = address.try_into().unwrap();
+    assert_eq!(address, std::net::Ipv4Addr::new(10, 0, 0, 1));
+    assert!(no_imports::DataTypes::Signature
+        .parse(b"PK\x03\x04")
+        .is_ok());
+}
+
+#[test]
+fn parses_without_serde() {
+    let (_, count) = no_serde::DataTypes::Count.parse(&[2, 0]).unwrap();
+    assert_eq!(count, no_serde::DataTypesCast::Count(2));
+}
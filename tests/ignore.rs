@@ -0,0 +1,34 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => u16)]
+    Count,
+    #[cast(ignore)]
+    Unsupported,
+}
+
+// An enum whose variants are all ignored generates an empty enum
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum Unparsed {
+    #[cast(ignore)]
+    Only,
+}
+
+#[test]
+fn ignored_variants_fail_to_parse() {
+    assert!(DataTypes::Unsupported.parse(&[1, 2]).is_err());
+    let (_, count) = DataTypes::Count.parse(&[1, 2]).unwrap();
+    assert_eq!(TryInto::<u16>::try_into(count).unwrap(), 0x0201);
+    assert!(Unparsed::Only.parse(&[1, 2]).is_err());
+}
+
+#[test]
+fn ignored_variants_are_named() {
+    assert!(matches!(
+        DataTypes::from_str("Unsupported"),
+        Ok(DataTypes::Unsupported)
+    ));
+}
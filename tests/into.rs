@@ -0,0 +1,107 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Mode {
+    Idle,
+    Armed,
+    Unknown(u8),
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = u8;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0 => Ok(Mode::Idle),
+            1 => Ok(Mode::Armed),
+            code => Err(code),
+        }
+    }
+}
+
+// A generic type, whose variants cannot be named as `Tagged<u8>::Unknown`
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Tagged<T> {
+    Even(T),
+    Unknown(T),
+}
+
+impl TryFrom<u8> for Tagged<u8> {
+    type Error = ();
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        if code.is_multiple_of(2) {
+            Ok(Tagged::Even(code))
+        } else {
+            Err(())
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_be_bytes => u8, into = Mode)]
+    StrictMode,
+    #[cast(from_be_bytes => u8, into = Mode, on_unknown = Unknown)]
+    LenientMode,
+    #[cast(from_be_bytes => u8, into = Mode, on_unknown = fail)]
+    FailingMode,
+    #[cast(from_be_bytes => [u8; 3], into = Mode, on_unknown = Unknown)]
+    Modes,
+    #[cast(from_be_bytes => [u8; 2], into = Mode)]
+    StrictModes,
+    #[cast(from_be_bytes => u8, into = Tagged<u8>, on_unknown = Unknown)]
+    Tag,
+}
+
+fn rejected<T>(result: nom::IResult<&[u8], T>) -> bool {
+    matches!(
+        result,
+        Err(nom::Err::Error(nom::error::Error {
+            code: nom::error::ErrorKind::MapOpt,
+            ..
+        }))
+    )
+}
+
+#[test]
+fn into_converts_known_codes() {
+    let (tail, mode) = DataTypes::StrictMode.parse(&[1, 9]).unwrap();
+    assert_eq!(tail, &[9]);
+    assert_eq!(mode.try_into(), Ok(Mode::Armed));
+}
+
+#[test]
+fn unknown_codes_fail_without_on_unknown() {
+    assert!(rejected(DataTypes::StrictMode.parse(&[5])));
+    assert!(rejected(DataTypes::FailingMode.parse(&[5])));
+}
+
+#[test]
+fn on_unknown_keeps_the_raw_code() {
+    let mode: Mode = decode(DataTypes::LenientMode.parse(&[5]));
+    assert_eq!(mode, Mode::Unknown(5));
+    let mode: Mode = decode(DataTypes::LenientMode.parse(&[0]));
+    assert_eq!(mode, Mode::Idle);
+}
+
+#[test]
+fn arrays_convert_every_code() {
+    let modes: [Mode; 3] = decode(DataTypes::Modes.parse(&[0, 1, 7]));
+    assert_eq!(modes, [Mode::Idle, Mode::Armed, Mode::Unknown(7)]);
+    let modes: [Mode; 2] = decode(DataTypes::StrictModes.parse(&[1, 0]));
+    assert_eq!(modes, [Mode::Armed, Mode::Idle]);
+    assert!(rejected(DataTypes::StrictModes.parse(&[1, 7])));
+}
+
+#[test]
+fn into_accepts_generic_types() {
+    let tag: Tagged<u8> = decode(DataTypes::Tag.parse(&[4]));
+    assert_eq!(tag, Tagged::Even(4));
+    let tag: Tagged<u8> = decode(DataTypes::Tag.parse(&[3]));
+    assert_eq!(tag, Tagged::Unknown(3));
+}
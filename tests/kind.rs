@@ -0,0 +1,53 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    Single,
+    #[cast(from_be_bytes => [u16; 2])]
+    Pair,
+    #[cast(from_le_bytes => u8, missing = 0xFF)]
+    Level,
+    #[cast(String)]
+    Text,
+    #[cast(skip = 1)]
+    Reserved,
+    #[cast(ipv4)]
+    Address,
+    #[cast(ipv6)]
+    Address6,
+}
+
+#[test]
+fn kind_maps_back_to_the_variant() {
+    for data_type in [
+        DataTypes::Single,
+        DataTypes::Pair,
+        DataTypes::Level,
+        DataTypes::Reserved,
+    ] {
+        let (_, value) = data_type.parse(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(value.kind(), data_type);
+    }
+    let (_, text) = DataTypes::Text.parse(b"abc").unwrap();
+    assert_eq!(text.kind(), DataTypes::Text);
+}
+
+#[test]
+fn type_name_is_written_as_in_the_attribute() {
+    let name = |data_type: DataTypes| data_type.parse(&[1, 2, 3, 4]).unwrap().1.type_name();
+    assert_eq!(name(DataTypes::Single), "f32");
+    assert_eq!(name(DataTypes::Pair), "[u16; 2]");
+    assert_eq!(name(DataTypes::Level), "Option<u8>");
+    assert_eq!(name(DataTypes::Text), "String");
+    assert_eq!(name(DataTypes::Reserved), "()");
+}
+
+#[test]
+fn type_name_omits_the_paths_of_generated_types() {
+    let (_, address) = DataTypes::Address.parse(&[10, 0, 0, 1]).unwrap();
+    assert_eq!(address.type_name(), "Ipv4Addr");
+    let (_, address) = DataTypes::Address6.parse(&[0; 16]).unwrap();
+    assert_eq!(address.type_name(), "Ipv6Addr");
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum LegacyTypes {
+    #[cast(ibm_float => f32)]
+    IbmSingle,
+    #[cast(ibm_float => f64)]
+    IbmDouble,
+    #[cast(ibm_float => [f32; 2])]
+    IbmSingleArr2,
+    #[cast(vax_f => f32)]
+    VaxF,
+    #[cast(vax_d => f64)]
+    VaxD,
+    #[cast(vax_g => f64)]
+    VaxG,
+    #[cast(mil1750a_32 => f32)]
+    Mil1750a32,
+    #[cast(mil1750a_48 => f64)]
+    Mil1750a48,
+}
+
+#[test]
+fn ibm_single() {
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::IbmSingle.parse(&[0x00, 0x00, 0x00, 0x00])),
+        0.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::IbmSingle.parse(&[0x41, 0x10, 0x00, 0x00])),
+        1.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::IbmSingle.parse(&[0x42, 0x64, 0x00, 0x00])),
+        100.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::IbmSingle.parse(&[0xC2, 0x76, 0xA0, 0x00])),
+        -118.625
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::IbmSingle.parse(&[0x40, 0x80, 0x00, 0x00])),
+        0.5
+    );
+}
+
+#[test]
+fn ibm_double() {
+    let one = 0x4110_0000_0000_0000u64.to_be_bytes();
+    assert_eq!(decode::<f64, _>(LegacyTypes::IbmDouble.parse(&one)), 1.0);
+    let negative = 0xC276_A000_0000_0000u64.to_be_bytes();
+    assert_eq!(
+        decode::<f64, _>(LegacyTypes::IbmDouble.parse(&negative)),
+        -118.625
+    );
+}
+
+#[test]
+fn ibm_single_array() {
+    let input = [0x41, 0x10, 0x00, 0x00, 0xC2, 0x76, 0xA0, 0x00];
+    let values: [f32; 2] = decode(LegacyTypes::IbmSingleArr2.parse(&input));
+    assert_eq!(values, [1.0, -118.625]);
+}
+
+#[test]
+fn vax_f() {
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::VaxF.parse(&[0x80, 0x40, 0x00, 0x00])),
+        1.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::VaxF.parse(&[0x80, 0xC0, 0x00, 0x00])),
+        -1.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::VaxF.parse(&[0x00, 0x40, 0x00, 0x00])),
+        0.5
+    );
+    // 3.0: exponent 130, fraction 0x400000
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::VaxF.parse(&[0x40, 0x41, 0x00, 0x00])),
+        3.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::VaxF.parse(&[0x00, 0x00, 0x00, 0x00])),
+        0.0
+    );
+    // Reserved operand
+    assert!(decode::<f32, _>(LegacyTypes::VaxF.parse(&[0x00, 0x80, 0x00, 0x00])).is_nan());
+}
+
+#[test]
+fn vax_d() {
+    let input = [0x80, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(decode::<f64, _>(LegacyTypes::VaxD.parse(&input)), 1.0);
+    let input = [0x40, 0xC1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(decode::<f64, _>(LegacyTypes::VaxD.parse(&input)), -3.0);
+}
+
+#[test]
+fn vax_g() {
+    let input = [0x10, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(decode::<f64, _>(LegacyTypes::VaxG.parse(&input)), 1.0);
+    let input = [0x28, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(decode::<f64, _>(LegacyTypes::VaxG.parse(&input)), -3.0);
+}
+
+#[test]
+fn mil1750a_32() {
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x40, 0x00, 0x00, 0x00])),
+        0.5
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x40, 0x00, 0x00, 0x01])),
+        1.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x50, 0x00, 0x00, 0x04])),
+        10.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x40, 0x00, 0x00, 0xFF])),
+        0.25
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x80, 0x00, 0x00, 0x00])),
+        -1.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x00, 0x00, 0x00, 0x00])),
+        0.0
+    );
+    assert_eq!(
+        decode::<f32, _>(LegacyTypes::Mil1750a32.parse(&[0x40, 0x00, 0x00, 0x7F])),
+        0.5 * 2f32.powi(127)
+    );
+}
+
+#[test]
+fn mil1750a_48() {
+    let input = [0x40, 0x00, 0x00, 0x01, 0x00, 0x00];
+    assert_eq!(decode::<f64, _>(LegacyTypes::Mil1750a48.parse(&input)), 1.0);
+    let input = [0x80, 0x00, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(
+        decode::<f64, _>(LegacyTypes::Mil1750a48.parse(&input)),
+        -1.0
+    );
+    // 0.5 + 2^-39 exercises the low 16 fraction bits
+    let input = [0x40, 0x00, 0x00, 0x00, 0x00, 0x01];
+    assert_eq!(
+        decode::<f64, _>(LegacyTypes::Mil1750a48.parse(&input)),
+        0.5 + 2f64.powi(-39)
+    );
+}
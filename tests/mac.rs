@@ -0,0 +1,33 @@
+#![cfg(feature = "macaddr")]
+
+use binary_type_cast::TypeCast;
+use macaddr::MacAddr6;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(mac)]
+    HardwareAddress,
+}
+
+#[test]
+fn mac_reads_six_bytes_in_order() {
+    let (tail, address) = DataTypes::HardwareAddress
+        .parse(&[0x00, 0x1B, 0x44, 0x11, 0x3A, 0xB7, 0x01])
+        .unwrap();
+    assert_eq!(tail, &[0x01]);
+    let address: MacAddr6 = address.try_into().unwrap();
+    assert_eq!(address, MacAddr6::new(0x00, 0x1B, 0x44, 0x11, 0x3A, 0xB7));
+    assert_eq!(address.to_string(), "00:1B:44:11:3A:B7");
+}
+
+#[test]
+fn mac_needs_six_bytes() {
+    assert!(DataTypes::HardwareAddress.parse(&[0; 5]).is_err());
+}
+
+#[test]
+fn mac_type_name_omits_the_crate_path() {
+    let (_, address) = DataTypes::HardwareAddress.parse(&[0; 6]).unwrap();
+    assert_eq!(address.type_name(), "MacAddr6");
+}
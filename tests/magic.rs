@@ -0,0 +1,105 @@
+use binary_type_cast::TypeCast;
+use nom::error::{ErrorKind, FromExternalError, ParseError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(magic = b"\x89HDF")]
+    HdfSignature,
+    #[cast(from_be_bytes => u32, expect = 0xCAFEBABE)]
+    ClassMagic,
+    #[cast(from_le_bytes => u16, expect = 0x0102)]
+    Version,
+}
+
+// The error of a failed parse, keeping the unexpected value when there is one
+#[derive(Debug, PartialEq)]
+enum HeaderError {
+    Nom(ErrorKind),
+    Unexpected(DataTypesCastUnexpectedValue),
+}
+
+impl ParseError<&[u8]> for HeaderError {
+    fn from_error_kind(_: &[u8], kind: ErrorKind) -> Self {
+        HeaderError::Nom(kind)
+    }
+
+    fn append(_: &[u8], _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl FromExternalError<&[u8], DataTypesCastUnexpectedValue> for HeaderError {
+    fn from_external_error(
+        _: &[u8],
+        _: ErrorKind,
+        unexpected: DataTypesCastUnexpectedValue,
+    ) -> Self {
+        HeaderError::Unexpected(unexpected)
+    }
+}
+
+fn unexpected(data_type: DataTypes, input: &[u8]) -> DataTypesCastUnexpectedValue {
+    match data_type.parse_with::<HeaderError>(input) {
+        Err(nom::Err::Error(HeaderError::Unexpected(unexpected))) => unexpected,
+        result => panic!("expected an unexpected value error, got {:?}", result),
+    }
+}
+
+#[test]
+fn matching_values_parse() {
+    let (tail, signature) = DataTypes::HdfSignature.parse(b"\x89HDF\r\n").unwrap();
+    assert_eq!(tail, b"\r\n");
+    assert!(matches!(signature, DataTypesCast::HdfSignature));
+    let (_, class) = DataTypes::ClassMagic
+        .parse(&[0xCA, 0xFE, 0xBA, 0xBE])
+        .unwrap();
+    assert!(matches!(class, DataTypesCast::ClassMagic(0xCAFEBABE)));
+}
+
+#[test]
+fn mismatches_are_recoverable_errors() {
+    assert!(matches!(
+        DataTypes::HdfSignature.parse(b"PK\x03\x04"),
+        Err(nom::Err::Error(_))
+    ));
+    assert!(matches!(
+        DataTypes::ClassMagic.parse(b"PK\x03\x04"),
+        Err(nom::Err::Error(_))
+    ));
+}
+
+#[test]
+fn magic_mismatch_reports_the_signatures() {
+    let mismatch = unexpected(DataTypes::HdfSignature, b"PK\x03\x04");
+    assert_eq!(
+        mismatch,
+        DataTypesCastUnexpectedValue {
+            variant: "HdfSignature",
+            expected: "b\"\\x89HDF\"".to_string(),
+            found: "b\"PK\\x03\\x04\"".to_string(),
+        }
+    );
+}
+
+#[test]
+fn expect_mismatch_reports_the_values() {
+    let mismatch = unexpected(DataTypes::ClassMagic, b"PK\x03\x04");
+    assert_eq!(
+        mismatch.to_string(),
+        "ClassMagic: expected 0xcafebabe, found 0x504b0304"
+    );
+    let mismatch = unexpected(DataTypes::Version, &[0x01, 0x02]);
+    assert_eq!(mismatch.expected, "0x102");
+    assert_eq!(mismatch.found, "0x201");
+}
+
+#[test]
+fn short_input_is_eof() {
+    assert_eq!(
+        DataTypes::HdfSignature
+            .parse_with::<HeaderError>(b"\x89H")
+            .err(),
+        Some(nom::Err::Error(HeaderError::Nom(ErrorKind::Eof)))
+    );
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_me_bytes => u32)]
+    PdpLong,
+    #[cast(from_me_bytes => i32)]
+    PdpSigned,
+    #[cast(from_me_bytes => u64)]
+    PdpQuad,
+    #[cast(from_me_bytes => f64)]
+    PdpDouble,
+    #[cast(from_me_bytes => [f32; 2])]
+    PlcReals,
+    #[cast(from_me_bytes => u16)]
+    Word,
+    #[cast(from_me_bytes => u8)]
+    Byte,
+}
+
+#[test]
+fn words_are_most_significant_first() {
+    let long: u32 = decode(DataTypes::PdpLong.parse(&[0x0B, 0x0A, 0x0D, 0x0C]));
+    assert_eq!(long, 0x0A0B0C0D);
+    let signed: i32 = decode(DataTypes::PdpSigned.parse(&[0xFF, 0xFF, 0xFE, 0xFF]));
+    assert_eq!(signed, -2);
+}
+
+#[test]
+fn every_word_of_a_64_bit_value() {
+    let input = [0x02, 0x01, 0x04, 0x03, 0x06, 0x05, 0x08, 0x07];
+    let quad: u64 = decode(DataTypes::PdpQuad.parse(&input));
+    assert_eq!(quad, 0x0102030405060708);
+    // 1.0 is 0x3FF0000000000000
+    let input = [0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let double: f64 = decode(DataTypes::PdpDouble.parse(&input));
+    assert_eq!(double, 1.0);
+}
+
+#[test]
+fn arrays_of_floats() {
+    // 1.0 is 0x3F800000 and -2.0 is 0xC0000000
+    let input = [0x80, 0x3F, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00];
+    let reals: [f32; 2] = decode(DataTypes::PlcReals.parse(&input));
+    assert_eq!(reals, [1.0, -2.0]);
+}
+
+#[test]
+fn single_words_read_as_little_endian() {
+    let word: u16 = decode(DataTypes::Word.parse(&[0x34, 0x12]));
+    assert_eq!(word, 0x1234);
+    let byte: u8 = decode(DataTypes::Byte.parse(&[0x7F]));
+    assert_eq!(byte, 0x7F);
+}
@@ -0,0 +1,79 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => u16)]
+    Count,
+    #[cast(from_be_bytes => i32)]
+    Offset,
+    #[cast(from_le_bytes => f32)]
+    Single,
+    #[cast(from_le_bytes => u64)]
+    Timestamp,
+    #[cast(from_le_bytes => u8, missing = 0xFF)]
+    Level,
+    #[cast(from_be_bytes => [i16; 2])]
+    Pair,
+    #[cast(from_le_bytes => [u8; 3], missing = 0)]
+    Levels,
+    #[cast(String)]
+    Text,
+}
+
+#[test]
+fn scalars_widen_to_f64_and_i128() {
+    let (_, count) = DataTypes::Count.parse(&[0x34, 0x12]).unwrap();
+    assert_eq!(count.to_f64(), Some(4660.0));
+    assert_eq!(count.to_i128(), Some(4660));
+    assert_eq!(count.to_f64_vec(), Some(vec![4660.0]));
+
+    let (_, offset) = DataTypes::Offset.parse(&(-7i32).to_be_bytes()).unwrap();
+    assert_eq!(offset.to_f64(), Some(-7.0));
+    assert_eq!(offset.to_i128(), Some(-7));
+
+    let (_, single) = DataTypes::Single.parse(&1.5f32.to_le_bytes()).unwrap();
+    assert_eq!(single.to_f64(), Some(1.5));
+    assert_eq!(single.to_i128(), None);
+}
+
+#[test]
+fn u64_is_exact_as_i128_and_rounded_as_f64() {
+    let (_, timestamp) = DataTypes::Timestamp.parse(&u64::MAX.to_le_bytes()).unwrap();
+    assert_eq!(timestamp.to_i128(), Some(u64::MAX as i128));
+    assert_eq!(timestamp.to_f64(), Some(18446744073709551616.0));
+}
+
+#[test]
+fn missing_values_are_none_or_nan() {
+    let (_, missing) = DataTypes::Level.parse(&[0xFF]).unwrap();
+    assert_eq!(missing.to_f64(), None);
+    assert_eq!(missing.to_i128(), None);
+    assert!(missing.to_f64_vec().unwrap()[0].is_nan());
+
+    let (_, level) = DataTypes::Level.parse(&[3]).unwrap();
+    assert_eq!(level.to_f64(), Some(3.0));
+    assert_eq!(level.to_i128(), Some(3));
+
+    let (_, levels) = DataTypes::Levels.parse(&[1, 0, 2]).unwrap();
+    let levels = levels.to_f64_vec().unwrap();
+    assert_eq!(levels[0], 1.0);
+    assert!(levels[1].is_nan());
+    assert_eq!(levels[2], 2.0);
+}
+
+#[test]
+fn arrays_widen_to_f64_vec_only() {
+    let (_, pair) = DataTypes::Pair.parse(&[0xFF, 0xFE, 0, 5]).unwrap();
+    assert_eq!(pair.to_f64_vec(), Some(vec![-2.0, 5.0]));
+    assert_eq!(pair.to_f64(), None);
+    assert_eq!(pair.to_i128(), None);
+}
+
+#[test]
+fn non_numeric_values_are_none() {
+    let (_, text) = DataTypes::Text.parse(b"abc").unwrap();
+    assert_eq!(text.to_f64(), None);
+    assert_eq!(text.to_i128(), None);
+    assert_eq!(text.to_f64_vec(), None);
+}
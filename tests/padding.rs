@@ -0,0 +1,90 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+const NO_ALIGNMENT: usize = 0;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum Reserved {
+    #[cast(skip = 3)]
+    Gap,
+    #[cast(skip = 2, zeroed)]
+    GapZero,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum Aligned {
+    #[cast(skip = 1)]
+    Gap,
+    #[cast(align = 8)]
+    Padding,
+    #[cast(align = 4, zeroed)]
+    PaddingZero,
+    #[cast(align = NO_ALIGNMENT)]
+    Unaligned,
+}
+
+#[test]
+fn skip_consumes_a_fixed_number_of_bytes() {
+    let (tail, gap) = Reserved::Gap.parse(&[1, 2, 3, 4]).unwrap();
+    assert_eq!(tail, &[4]);
+    assert!(matches!(gap, ReservedCast::Gap));
+    assert!(Reserved::Gap.parse(&[1, 2]).is_err());
+}
+
+#[test]
+fn parse_at_skips_regardless_of_the_offset() {
+    let (tail, _) = Reserved::Gap.parse_at(&[1, 2, 3, 4], 5).unwrap();
+    assert_eq!(tail, &[4]);
+    let (tail, _) = Aligned::Gap.parse_at(&[1, 2], 7).unwrap();
+    assert_eq!(tail, &[2]);
+}
+
+#[test]
+fn zeroed_requires_every_byte_to_be_zero() {
+    let (tail, _) = Reserved::GapZero.parse(&[0, 0, 7]).unwrap();
+    assert_eq!(tail, &[7]);
+    assert!(matches!(
+        Reserved::GapZero.parse(&[0, 1, 7]),
+        Err(nom::Err::Error(nom::error::Error {
+            code: nom::error::ErrorKind::Verify,
+            ..
+        }))
+    ));
+
+    let (tail, _) = Aligned::PaddingZero.parse_at(&[0, 0, 0, 9], 1).unwrap();
+    assert_eq!(tail, &[9]);
+    assert!(Aligned::PaddingZero.parse_at(&[0, 5, 0, 9], 1).is_err());
+}
+
+#[test]
+fn align_pads_up_to_the_next_multiple_of_the_offset() {
+    let input = [0u8; 10];
+    let consumed = |offset| 10 - Aligned::Padding.parse_at(&input, offset).unwrap().0.len();
+    assert_eq!(consumed(0), 0);
+    assert_eq!(consumed(3), 5);
+    assert_eq!(consumed(8), 0);
+    assert_eq!(consumed(10), 6);
+    // The length of the input does not matter, only its offset
+    let (tail, padding) = Aligned::Padding.parse_at(&[0; 3], 6).unwrap();
+    assert_eq!(tail, &[0]);
+    assert!(matches!(padding, AlignedCast::Padding));
+}
+
+#[test]
+fn align_fails_when_the_padding_is_missing() {
+    assert!(matches!(
+        Aligned::Padding.parse_at(&[0; 2], 3),
+        Err(nom::Err::Error(_))
+    ));
+}
+
+#[test]
+fn zero_alignment_fails_instead_of_panicking() {
+    assert!(matches!(
+        Aligned::Unaligned.parse_at(&[0; 4], 3),
+        Err(nom::Err::Failure(nom::error::Error {
+            code: nom::error::ErrorKind::Fail,
+            ..
+        }))
+    ));
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => f32, missing = -999.0, invalid = -9999.0)]
+    Temperature,
+    #[cast(from_be_bytes => u16, missing = 0xFFFF)]
+    Count,
+    #[cast(from_le_bytes => i8, invalid = -128)]
+    Offset,
+    #[cast(from_le_bytes => f32, missing = f32::NAN)]
+    Reading,
+    #[cast(from_le_bytes => [f64; 3], missing = f64::NAN)]
+    Samples,
+}
+
+#[test]
+fn float_sentinels() {
+    let temperature =
+        |value: f32| decode::<Option<f32>, _>(DataTypes::Temperature.parse(&value.to_le_bytes()));
+    assert_eq!(temperature(21.5), Some(21.5));
+    assert_eq!(temperature(-999.0), None);
+    assert_eq!(temperature(-9999.0), None);
+    assert_eq!(temperature(-99.0), Some(-99.0));
+}
+
+#[test]
+fn integer_sentinels() {
+    assert_eq!(
+        decode::<Option<u16>, _>(DataTypes::Count.parse(&[0xFF, 0xFF])),
+        None
+    );
+    assert_eq!(
+        decode::<Option<u16>, _>(DataTypes::Count.parse(&[0xFF, 0xFE])),
+        Some(0xFFFE)
+    );
+    assert_eq!(
+        decode::<Option<i8>, _>(DataTypes::Offset.parse(&[0x80])),
+        None
+    );
+    assert_eq!(
+        decode::<Option<i8>, _>(DataTypes::Offset.parse(&[0x81])),
+        Some(-127)
+    );
+}
+
+#[test]
+fn nan_sentinel_matches_any_nan() {
+    // The quiet NaN, a NaN with a payload and a negative NaN
+    for bits in [0x7FC0_0000u32, 0x7F80_0001, 0xFFC0_0000] {
+        assert!(f32::from_bits(bits).is_nan());
+        assert_eq!(
+            decode::<Option<f32>, _>(DataTypes::Reading.parse(&bits.to_le_bytes())),
+            None
+        );
+    }
+    assert_eq!(
+        decode::<Option<f32>, _>(DataTypes::Reading.parse(&f32::INFINITY.to_le_bytes())),
+        Some(f32::INFINITY)
+    );
+}
+
+#[test]
+fn arrays_hold_an_option_per_element() {
+    let input: Vec<u8> = [1.5, f64::from_bits(0x7FF8_0000_0000_0001), -2.0]
+        .iter()
+        .flat_map(|value: &f64| value.to_le_bytes())
+        .collect();
+    let samples = decode::<[Option<f64>; 3], _>(DataTypes::Samples.parse(&input));
+    assert_eq!(samples, [Some(1.5), None, Some(-2.0)]);
+}
+
+#[test]
+fn sentinel_type_names_are_options() {
+    let (_, temperature) = DataTypes::Temperature.parse(&[0; 4]).unwrap();
+    assert_eq!(temperature.type_name(), "Option<f32>");
+    let (_, samples) = DataTypes::Samples.parse(&[0; 24]).unwrap();
+    assert_eq!(samples.type_name(), "[Option<f64>; 3]");
+}
@@ -0,0 +1,53 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(from_le_bytes => f32)]
+    Single,
+    #[cast(from_be_bytes => [u16; 2])]
+    Pair,
+    #[cast(String)]
+    Text,
+}
+
+#[test]
+fn try_from_takes_the_value() {
+    let (_, single) = DataTypes::Single.parse(&0.5f32.to_le_bytes()).unwrap();
+    let (_, text) = DataTypes::Text.parse(b"abc").unwrap();
+    assert_eq!(f32::try_from(single), Ok(0.5));
+    assert_eq!(String::try_from(text), Ok("abc".to_string()));
+    assert_eq!(
+        f32::try_from(DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap().1),
+        Err("Cannot convert non-compatible DataTypesCast into f32".to_string())
+    );
+}
+
+#[test]
+fn try_from_borrows_by_reference() {
+    let (_, pair) = DataTypes::Pair.parse(&[0, 1, 0, 2]).unwrap();
+    assert_eq!(<&[u16; 2]>::try_from(&pair), Ok(&[1, 2]));
+    assert!(<&f32>::try_from(&pair).is_err());
+    // The value is still there after borrowing it
+    assert_eq!(<[u16; 2]>::try_from(pair), Ok([1, 2]));
+}
+
+#[test]
+fn try_into_keeps_working() {
+    let (_, single) = DataTypes::Single.parse(&0.5f32.to_le_bytes()).unwrap();
+    let borrowed: &f32 = (&single).try_into().unwrap();
+    assert_eq!(*borrowed, 0.5);
+    let owned: Result<f32, _> = single.try_into();
+    assert_eq!(owned, Ok(0.5));
+}
+
+fn single(value: &DataTypesCast) -> Result<f32, String> {
+    let single: &f32 = value.try_into()?;
+    Ok(*single)
+}
+
+#[test]
+fn conversions_compose_with_the_question_mark() {
+    let (_, text) = DataTypes::Text.parse(b"abc").unwrap();
+    assert!(single(&text).is_err());
+}
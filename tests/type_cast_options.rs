@@ -0,0 +1,109 @@
+use binary_type_cast::TypeCast;
+use serde::{Deserialize, Serialize};
+
+// Without serde, the generated enum only derives what is asked for
+#[derive(Clone, Copy, Debug, TypeCast)]
+#[type_cast(derive(Clone, Copy, Debug, PartialEq))]
+pub enum PlainTypes {
+    #[cast(from_le_bytes => u16)]
+    Count,
+    #[cast(from_be_bytes => [u8; 2])]
+    Pair,
+}
+
+#[derive(Clone, Copy, Debug, TypeCast)]
+#[type_cast(
+    derive(Clone, Debug, Serialize, Deserialize),
+    attr(serde(rename_all = "snake_case"))
+)]
+pub enum SerdeTypes {
+    #[cast(from_le_bytes => f32)]
+    Temperature,
+}
+
+#[test]
+fn configured_derives() {
+    let (_, count) = PlainTypes::Count.parse(&[1, 0]).unwrap();
+    assert_eq!(count, PlainTypesCast::Count(1));
+    let (_, pair) = PlainTypes::Pair.parse(&[1, 2]).unwrap();
+    let copy = pair;
+    assert_eq!(pair, copy);
+}
+
+#[test]
+fn forwarded_attributes() {
+    let (_, temperature) = SerdeTypes::Temperature
+        .parse(&1.5f32.to_le_bytes())
+        .unwrap();
+    assert_eq!(TryInto::<f32>::try_into(temperature).unwrap(), 1.5);
+}
+
+mod telemetry {
+    use binary_type_cast::TypeCast;
+
+    #[derive(Clone, Copy, Debug, TypeCast)]
+    #[type_cast(name = "DecodedValue", vis = "pub(crate)")]
+    pub enum DataTypes {
+        #[cast(from_be_bytes => u32)]
+        Counter,
+    }
+}
+
+mod housekeeping {
+    use binary_type_cast::TypeCast;
+
+    #[derive(Clone, Copy, Debug, TypeCast)]
+    #[type_cast(name = "DecodedValue", vis = "pub(crate)")]
+    pub enum DataTypes {
+        #[cast(from_le_bytes => u32)]
+        Counter,
+    }
+}
+
+// The error types generated next to the enum are named after the configured name as well
+mod frames {
+    use binary_type_cast::TypeCast;
+
+    #[derive(Clone, Copy, Debug, TypeCast)]
+    #[type_cast(name = "DecodedValue")]
+    pub enum DataTypes {
+        #[cast(from_be_bytes => u16, expect = 0xCAFE)]
+        Magic,
+        #[cast(fletcher16, over = 0..2)]
+        Checksum,
+    }
+}
+
+#[test]
+fn helper_types_follow_the_custom_name() {
+    let unexpected = frames::DecodedValueUnexpectedValue {
+        variant: "Magic",
+        expected: "0xcafe".to_string(),
+        found: "0xbeef".to_string(),
+    };
+    assert_eq!(
+        unexpected.to_string(),
+        "Magic: expected 0xcafe, found 0xbeef"
+    );
+    let mismatch = frames::DecodedValueChecksumMismatch {
+        variant: "Checksum",
+        expected: 1,
+        actual: 2,
+    };
+    assert_eq!(
+        mismatch.to_string(),
+        "Checksum checksum mismatch: expected 0x1, computed 0x2"
+    );
+    let (_, magic) = frames::DataTypes::Magic.parse(&[0xCA, 0xFE]).unwrap();
+    assert!(matches!(magic, frames::DecodedValue::Magic(0xCAFE)));
+}
+
+#[test]
+fn custom_name_and_visibility() {
+    let (_, counter) = telemetry::DataTypes::Counter.parse(&[0, 0, 0, 1]).unwrap();
+    assert!(matches!(counter, telemetry::DecodedValue::Counter(1)));
+    let (_, counter) = housekeeping::DataTypes::Counter
+        .parse(&[1, 0, 0, 0])
+        .unwrap();
+    assert_eq!(TryInto::<u32>::try_into(counter).unwrap(), 1);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "uuid")]
+
+mod common;
+
+use binary_type_cast::TypeCast;
+use common::decode;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeCast)]
+pub enum DataTypes {
+    #[cast(uuid)]
+    AssetId,
+    #[cast(guid_le)]
+    ClassId,
+}
+
+// 00112233-4455-6677-8899-aabbccddeeff as stored by Windows, with the first three fields little endian
+const GUID_BYTES: [u8; 16] = [
+    0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+];
+
+#[test]
+fn uuid_reads_rfc_4122_byte_order() {
+    let asset_id: Uuid = decode(DataTypes::AssetId.parse(&GUID_BYTES));
+    assert_eq!(asset_id, Uuid::from_bytes(GUID_BYTES));
+    assert_eq!(asset_id.to_string(), "33221100-5544-7766-8899-aabbccddeeff");
+}
+
+#[test]
+fn guid_le_swaps_the_first_three_fields() {
+    let class_id: Uuid = decode(DataTypes::ClassId.parse(&GUID_BYTES));
+    assert_eq!(class_id, Uuid::from_bytes_le(GUID_BYTES));
+    assert_eq!(class_id.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+}
+
+#[test]
+fn uuids_need_16_bytes() {
+    assert!(DataTypes::ClassId.parse(&GUID_BYTES[..15]).is_err());
+}
+
+#[test]
+fn uuid_type_name_omits_the_crate_path() {
+    let (_, class_id) = DataTypes::ClassId.parse(&GUID_BYTES).unwrap();
+    assert_eq!(class_id.type_name(), "Uuid");
+    assert_eq!(class_id.kind(), DataTypes::ClassId);
+}